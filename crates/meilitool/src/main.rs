@@ -1,19 +1,37 @@
-use std::fs::{read_dir, read_to_string, remove_file, File};
-use std::io::BufWriter;
-use std::path::PathBuf;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::fs::{read_dir, read_to_string, remove_file, rename, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use clap::{Parser, Subcommand};
 use dump::{DumpWriter, IndexMetadata};
 use file_store::FileStore;
+use fst::Streamer;
 use meilisearch_auth::AuthController;
-use meilisearch_types::heed::types::{SerdeJson, Str};
-use meilisearch_types::heed::{Database, Env, EnvOpenOptions, RoTxn, RwTxn, Unspecified};
+use meilisearch_types::heed::types::{Bytes, SerdeJson, Str, U8};
+use meilisearch_types::heed::{
+    BoxedError, BytesDecode, CompactionOption, Database, Env, EnvFlags, EnvOpenOptions, RoTxn,
+    RwTxn, Unspecified,
+};
 use meilisearch_types::milli::documents::{obkv_to_object, DocumentsBatchReader};
-use meilisearch_types::milli::{obkv_to_json, BEU32};
+use meilisearch_types::milli::heed_codec::facet::{
+    FacetGroupKey, FacetGroupKeyCodec, FacetGroupValue, FacetGroupValueCodec,
+    FieldDocIdFacetF64Codec, FieldDocIdFacetStringCodec, FieldIdCodec, OrderedF64Codec,
+};
+use meilisearch_types::milli::heed_codec::{
+    BEU16StrCodec, FieldIdWordCountCodec, FstSetCodec, ObkvCodec, StrBEU16Codec, StrRefCodec,
+    U8StrStrCodec,
+};
+use meilisearch_types::milli::index::{db_name, DatabaseSizes, DatabaseStats, ReadOnlyIndex};
+use meilisearch_types::milli::{
+    obkv_to_json, CboRoaringBitmapCodec, CboRoaringBitmapLenCodec, BEU32,
+};
 use meilisearch_types::tasks::{Status, Task};
 use meilisearch_types::versioning::{get_version, parse_version};
 use meilisearch_types::Index;
+use roaring::RoaringBitmap;
 use time::macros::format_description;
 use time::OffsetDateTime;
 use upgrade::OfflineUpgrade;
@@ -73,11 +91,95 @@ enum Command {
     ///
     /// Supported upgrade paths:
     ///
-    /// - v1.9.x -> v1.10.x -> v1.11.x
+    /// - v1.9.x -> v1.10.x -> v1.11.x -> v1.12.x
     OfflineUpgrade {
         #[arg(long)]
         target_version: String,
     },
+
+    /// Prints the number of entries and the pages used by each of the databases of an index.
+    ///
+    /// Can be useful to understand which databases take most of the place in an index.
+    IndexDatabaseStats {
+        /// The uid of the index to inspect.
+        #[arg(long)]
+        index_name: String,
+
+        /// Also prints the size of the keys and values of each database.
+        ///
+        /// Note that every entry of the index is read.
+        #[arg(long)]
+        with_sizes: bool,
+    },
+
+    /// Exports the words of an index along with the number of documents containing them.
+    ///
+    /// Every line contains a word, a tab and the number of documents, in lexicographic order.
+    ExportIndexWords {
+        /// The uid of the index to export the words from.
+        #[arg(long)]
+        index_name: String,
+    },
+
+    /// Exports the entries of one of the databases of an index with decoded keys and values.
+    ///
+    /// Every line contains the decoded key, a tab and the decoded value, in key order.
+    /// The docids are exported as their number of documents and the documents as JSON objects.
+    /// Only the size of the values of the `main` and `vector-arroy` databases is exported.
+    ExportIndexEntries {
+        /// The uid of the index to export the entries from.
+        #[arg(long)]
+        index_name: String,
+
+        /// The name of the database to export, e.g. `word-pair-proximity-docids`.
+        #[arg(long)]
+        database: String,
+    },
+
+    /// Prints the entries with the biggest values of the word databases of an index.
+    ///
+    /// Can be useful to find the stop-word-like terms that blow up the size of an index.
+    /// The word docids, word pair proximity docids and word position docids databases
+    /// are inspected.
+    IndexHeaviestKeys {
+        /// The uid of the index to inspect.
+        #[arg(long)]
+        index_name: String,
+
+        /// The number of entries to print.
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+
+    /// Exports the documents of an index as NDJSON on the standard output.
+    ///
+    /// Can be useful to re-index the documents in another index or instance.
+    ExportIndexDocuments {
+        /// The uid of the index to export the documents from.
+        #[arg(long)]
+        index_name: String,
+    },
+
+    /// Checks the consistency of the databases of an index and prints every violation found.
+    ///
+    /// The words of the word docids databases must be in the words FST, the keys must be
+    /// well-formed, the docids must deserialize and only reference the documents of the index.
+    /// Exits with an error if any violation is found.
+    VerifyIndex {
+        /// The uid of the index to verify.
+        #[arg(long)]
+        index_name: String,
+    },
+
+    /// Compacts an index to reclaim the disk space of its free pages.
+    ///
+    /// Make sure to run this command when Meilisearch is not running!
+    /// The index is copied without its free pages and the copy replaces the original file.
+    CompactIndex {
+        /// The uid of the index to compact.
+        #[arg(long)]
+        index_name: String,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
@@ -94,6 +196,19 @@ fn main() -> anyhow::Result<()> {
             let target_version = parse_version(&target_version).context("While parsing `--target-version`. Make sure `--target-version` is in the format MAJOR.MINOR.PATCH")?;
             OfflineUpgrade { db_path, current_version: detected_version, target_version }.upgrade()
         }
+        Command::IndexDatabaseStats { index_name, with_sizes } => {
+            index_database_stats(db_path, index_name, with_sizes)
+        }
+        Command::ExportIndexWords { index_name } => export_index_words(db_path, index_name),
+        Command::ExportIndexEntries { index_name, database } => {
+            export_index_entries(db_path, index_name, database)
+        }
+        Command::IndexHeaviestKeys { index_name, limit } => {
+            index_heaviest_keys(db_path, index_name, limit)
+        }
+        Command::ExportIndexDocuments { index_name } => export_index_documents(db_path, index_name),
+        Command::VerifyIndex { index_name } => verify_index(db_path, index_name),
+        Command::CompactIndex { index_name } => compact_index(db_path, index_name),
     }
 }
 
@@ -152,6 +267,568 @@ fn clear_task_queue(db_path: PathBuf) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Returns the path of the index with the given uid, from the database located at `db_path`.
+fn index_path(db_path: &Path, index_name: &str) -> anyhow::Result<PathBuf> {
+    let index_scheduler_path = db_path.join("tasks");
+    let mut options = EnvOpenOptions::new();
+    options.max_dbs(100);
+    // Only the index mapping is read, the tasks database is never written.
+    unsafe { options.flags(EnvFlags::READ_ONLY) };
+    let env = unsafe { options.open(&index_scheduler_path) }
+        .with_context(|| format!("While trying to open {:?}", index_scheduler_path.display()))?;
+
+    let rtxn = env.read_txn()?;
+    let index_mapping: Database<Str, UuidCodec> =
+        try_opening_database(&env, &rtxn, "index-mapping")?;
+    let uuid = index_mapping
+        .get(&rtxn, index_name)?
+        .with_context(|| format!("Index {index_name:?} not found"))?;
+
+    Ok(db_path.join("indexes").join(uuid.to_string()))
+}
+
+/// Opens the index with the given uid to write into it.
+fn open_index(db_path: &Path, index_name: &str) -> anyhow::Result<Index> {
+    let index_path = index_path(db_path, index_name)?;
+    Index::new(EnvOpenOptions::new(), &index_path).with_context(|| {
+        format!("While trying to open the index at path {:?}", index_path.display())
+    })
+}
+
+/// Opens the index with the given uid to inspect it, without ever taking its writer lock.
+fn open_index_read_only(db_path: &Path, index_name: &str) -> anyhow::Result<ReadOnlyIndex> {
+    let index_path = index_path(db_path, index_name)?;
+    Index::open_read_only(EnvOpenOptions::new(), &index_path).with_context(|| {
+        format!("While trying to open the index at path {:?}", index_path.display())
+    })
+}
+
+/// Returns the database with the given name of an index, decoded with the given codecs.
+fn index_database<KC, DC>(index: &ReadOnlyIndex, name: &str) -> anyhow::Result<Database<KC, DC>> {
+    let database = index.database(name).with_context(|| format!("Unknown database {name:?}"))?;
+    Ok(database.remap_types())
+}
+
+/// Prints the number of entries and the pages of each of the databases of an index.
+fn index_database_stats(
+    db_path: PathBuf,
+    index_name: String,
+    with_sizes: bool,
+) -> anyhow::Result<()> {
+    let index = open_index_read_only(&db_path, &index_name)?;
+    let rtxn = index.read_txn()?;
+    let stats = index.database_stats(&rtxn).context("While reading the databases statistics")?;
+    let sizes = if with_sizes {
+        Some(index.database_sizes(&rtxn).context("While reading the databases sizes")?)
+    } else {
+        None
+    };
+
+    for (name, database_stats) in stats {
+        let used_size = database_stats.used_size();
+        let DatabaseStats {
+            number_of_entries,
+            depth,
+            page_size: _,
+            branch_pages,
+            leaf_pages,
+            overflow_pages,
+        } = database_stats;
+        print!(
+            "{name}: {number_of_entries} entries, depth {depth}, {branch_pages} branch pages, {leaf_pages} leaf pages, {overflow_pages} overflow pages ({used_size} bytes)"
+        );
+        match sizes.as_ref().and_then(|sizes| sizes.get(name)) {
+            Some(DatabaseSizes { total_key_size, total_value_size }) => {
+                println!(", {total_key_size} bytes of keys, {total_value_size} bytes of values")
+            }
+            None => println!(),
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes every word of an index and its number of documents on the standard output.
+fn export_index_words(db_path: PathBuf, index_name: String) -> anyhow::Result<()> {
+    let index = open_index_read_only(&db_path, &index_name)?;
+    let rtxn = index.read_txn()?;
+
+    let words_fst = index.words_fst(&rtxn).context("While reading the words FST")?;
+    let word_docids: Database<Str, CboRoaringBitmapCodec> =
+        index_database(&index, db_name::WORD_DOCIDS)?;
+    let exact_word_docids: Database<Str, CboRoaringBitmapCodec> =
+        index_database(&index, db_name::EXACT_WORD_DOCIDS)?;
+
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let mut stream = words_fst.stream();
+    while let Some(word) = stream.next() {
+        let word = std::str::from_utf8(word).context("While decoding a word of the words FST")?;
+        // a document can contain the same word in exact and non-exact attributes
+        let mut docids = word_docids.get(&rtxn, word)?.unwrap_or_default();
+        if let Some(exact_docids) = exact_word_docids.get(&rtxn, word)? {
+            docids |= exact_docids;
+        }
+        writeln!(stdout, "{word}\t{}", docids.len())?;
+    }
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Writes the decoded entries of a database of an index on the standard output.
+fn export_index_entries(
+    db_path: PathBuf,
+    index_name: String,
+    database: String,
+) -> anyhow::Result<()> {
+    let index = open_index_read_only(&db_path, &index_name)?;
+    let rtxn = index.read_txn()?;
+    let fields_ids_map = index.fields_ids_map(&rtxn)?;
+    let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
+
+    // The keys decoders.
+    let word = |bytes: &[u8]| Str::bytes_decode(bytes).map(ToString::to_string);
+    let word_pair = |bytes: &[u8]| {
+        U8StrStrCodec::bytes_decode(bytes)
+            .map(|(proximity, left, right)| format!("{left} {right} (proximity {proximity})"))
+    };
+    let word_position = |bytes: &[u8]| {
+        StrBEU16Codec::bytes_decode(bytes)
+            .map(|(word, position)| format!("{word} (position {position})"))
+    };
+    let word_fid = |bytes: &[u8]| {
+        StrBEU16Codec::bytes_decode(bytes).map(|(word, fid)| format!("{word} (field id {fid})"))
+    };
+    let fid_word_count = |bytes: &[u8]| {
+        FieldIdWordCountCodec::bytes_decode(bytes)
+            .map(|(fid, count)| format!("field id {fid} ({count} words)"))
+    };
+    let fid = |bytes: &[u8]| FieldIdCodec::bytes_decode(bytes).map(|fid| format!("field id {fid}"));
+    let fid_string = |bytes: &[u8]| {
+        BEU16StrCodec::bytes_decode(bytes).map(|(fid, string)| format!("field id {fid}, {string}"))
+    };
+    let facet_f64_group = |bytes: &[u8]| {
+        FacetGroupKeyCodec::<OrderedF64Codec>::bytes_decode(bytes).map(
+            |FacetGroupKey { field_id, level, left_bound }| {
+                format!("field id {field_id}, level {level}, from {left_bound}")
+            },
+        )
+    };
+    let facet_string_group = |bytes: &[u8]| {
+        FacetGroupKeyCodec::<StrRefCodec>::bytes_decode(bytes).map(
+            |FacetGroupKey { field_id, level, left_bound }| {
+                format!("field id {field_id}, level {level}, from {left_bound}")
+            },
+        )
+    };
+    let fid_docid_f64 = |bytes: &[u8]| {
+        FieldDocIdFacetF64Codec::bytes_decode(bytes)
+            .map(|(fid, docid, number)| format!("field id {fid}, document {docid}, {number}"))
+    };
+    let fid_docid_string = |bytes: &[u8]| {
+        FieldDocIdFacetStringCodec::bytes_decode(bytes)
+            .map(|(fid, docid, string)| format!("field id {fid}, document {docid}, {string}"))
+    };
+    let docid = |bytes: &[u8]| BEU32::bytes_decode(bytes).map(|docid| docid.to_string());
+    let hexadecimal = |bytes: &[u8]| -> Result<String, BoxedError> {
+        Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+    };
+
+    // The values decoders.
+    let docids_count =
+        |bytes: &[u8]| CboRoaringBitmapLenCodec::bytes_decode(bytes).map(|len| len.to_string());
+    let facet_group = |bytes: &[u8]| {
+        FacetGroupValueCodec::bytes_decode(bytes)
+            .map(|FacetGroupValue { size, bitmap }| format!("{} (group size {size})", bitmap.len()))
+    };
+    let facet_values_count =
+        |bytes: &[u8]| FstSetCodec::bytes_decode(bytes).map(|fst| format!("{} values", fst.len()));
+    let original_strings = |bytes: &[u8]| -> Result<String, BoxedError> {
+        let strings = SerdeJson::<BTreeSet<String>>::bytes_decode(bytes)?;
+        Ok(serde_json::to_string(&strings)?)
+    };
+    let string = word;
+    let embedder_id = |bytes: &[u8]| U8::bytes_decode(bytes).map(|id| id.to_string());
+    let nothing = |_: &[u8]| -> Result<String, BoxedError> { Ok(String::new()) };
+    let size =
+        |bytes: &[u8]| -> Result<String, BoxedError> { Ok(format!("{} bytes", bytes.len())) };
+    let document = |bytes: &[u8]| -> Result<String, BoxedError> {
+        let document = obkv_to_json(&all_fields, &fields_ids_map, ObkvCodec::bytes_decode(bytes)?)?;
+        Ok(serde_json::to_string(&document)?)
+    };
+
+    type Format<'a> = &'a dyn Fn(&[u8]) -> Result<String, BoxedError>;
+    let (format_key, format_value): (Format, Format) = match database.as_str() {
+        // The main database contains values of many types, only their size is exported.
+        db_name::MAIN => (&word, &size),
+        db_name::EXTERNAL_DOCUMENTS_IDS => (&word, &docid),
+        db_name::WORD_DOCIDS
+        | db_name::EXACT_WORD_DOCIDS
+        | db_name::WORD_PREFIX_DOCIDS
+        | db_name::EXACT_WORD_PREFIX_DOCIDS => (&word, &docids_count),
+        db_name::WORD_PAIR_PROXIMITY_DOCIDS => (&word_pair, &docids_count),
+        db_name::WORD_POSITION_DOCIDS | db_name::WORD_PREFIX_POSITION_DOCIDS => {
+            (&word_position, &docids_count)
+        }
+        db_name::WORD_FIELD_ID_DOCIDS | db_name::WORD_PREFIX_FIELD_ID_DOCIDS => {
+            (&word_fid, &docids_count)
+        }
+        db_name::FIELD_ID_WORD_COUNT_DOCIDS => (&fid_word_count, &docids_count),
+        db_name::FACET_ID_EXISTS_DOCIDS
+        | db_name::FACET_ID_IS_NULL_DOCIDS
+        | db_name::FACET_ID_IS_EMPTY_DOCIDS => (&fid, &docids_count),
+        db_name::FACET_ID_F64_DOCIDS => (&facet_f64_group, &facet_group),
+        db_name::FACET_ID_STRING_DOCIDS => (&facet_string_group, &facet_group),
+        db_name::FACET_ID_NORMALIZED_STRING_STRINGS => (&fid_string, &original_strings),
+        db_name::FACET_ID_STRING_FST => (&fid, &facet_values_count),
+        db_name::FIELD_ID_DOCID_FACET_F64S => (&fid_docid_f64, &nothing),
+        db_name::FIELD_ID_DOCID_FACET_STRINGS => (&fid_docid_string, &string),
+        db_name::VECTOR_EMBEDDER_CATEGORY_ID => (&word, &embedder_id),
+        // The arroy entries are internal to the vector store, only their size is exported.
+        db_name::VECTOR_ARROY => (&hexadecimal, &size),
+        db_name::DOCUMENTS => (&docid, &document),
+        otherwise => anyhow::bail!("Unknown database {otherwise:?}"),
+    };
+    let entries: Database<Bytes, Bytes> = index_database(&index, &database)?;
+
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    for result in entries.iter(&rtxn)? {
+        let (key, value) =
+            result.with_context(|| format!("While reading the {database} database"))?;
+        let key = format_key(key).map_err(|e| anyhow::anyhow!(e))?;
+        let value = format_value(value)
+            .map_err(|e| anyhow::anyhow!(e))
+            .with_context(|| format!("While decoding the value of {key:?}"))?;
+        writeln!(stdout, "{key}\t{value}")?;
+    }
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Writes every document of an index as a JSON object per line on the standard output.
+fn export_index_documents(db_path: PathBuf, index_name: String) -> anyhow::Result<()> {
+    let index = open_index_read_only(&db_path, &index_name)?;
+    let rtxn = index.read_txn()?;
+
+    let fields_ids_map = index.fields_ids_map(&rtxn)?;
+    let all_fields: Vec<_> = fields_ids_map.iter().map(|(id, _)| id).collect();
+
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    for ret in index.all_documents(&rtxn)? {
+        let (id, doc) = ret?;
+        let document = obkv_to_json(&all_fields, &fields_ids_map, doc)
+            .with_context(|| format!("While converting the document {id} to JSON"))?;
+        serde_json::to_writer(&mut stdout, &document)?;
+        writeln!(stdout)?;
+    }
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Checks the invariants of the databases of an index and prints every violation found.
+fn verify_index(db_path: PathBuf, index_name: String) -> anyhow::Result<()> {
+    let index = open_index_read_only(&db_path, &index_name)?;
+    let rtxn = index.read_txn()?;
+
+    let mut violations = 0;
+    let mut report = |database: &str, message: String| {
+        violations += 1;
+        println!("{database}: {message}");
+    };
+
+    let documents_ids = index.documents_ids(&rtxn).context("While reading the documents ids")?;
+
+    // The documents ids and the documents database must reference the same documents.
+    let documents: Database<Bytes, Bytes> = index_database(&index, db_name::DOCUMENTS)?;
+    let mut stored_documents = RoaringBitmap::new();
+    for result in documents.iter(&rtxn)? {
+        let (key, _) = result.context("While reading the documents database")?;
+        match BEU32::bytes_decode(key) {
+            Ok(docid) => {
+                stored_documents.insert(docid);
+            }
+            Err(e) => report(db_name::DOCUMENTS, format!("malformed key {key:?}: {e}")),
+        }
+    }
+    for docid in &documents_ids - &stored_documents {
+        report(db_name::DOCUMENTS, format!("the document {docid} is missing"));
+    }
+    for docid in &stored_documents - &documents_ids {
+        report(db_name::DOCUMENTS, format!("the document {docid} is not in the documents ids"));
+    }
+
+    let external_documents_ids: Database<Bytes, Bytes> =
+        index_database(&index, db_name::EXTERNAL_DOCUMENTS_IDS)?;
+    for result in external_documents_ids.iter(&rtxn)? {
+        let (key, value) = result.context("While reading the external documents ids database")?;
+        let Ok(external_id) = Str::bytes_decode(key) else {
+            report(db_name::EXTERNAL_DOCUMENTS_IDS, format!("malformed key {key:?}"));
+            continue;
+        };
+        match BEU32::bytes_decode(value) {
+            Ok(docid) if !documents_ids.contains(docid) => report(
+                db_name::EXTERNAL_DOCUMENTS_IDS,
+                format!("{external_id:?} references the unknown document {docid}"),
+            ),
+            Ok(_) => (),
+            Err(e) => report(
+                db_name::EXTERNAL_DOCUMENTS_IDS,
+                format!("malformed document id for {external_id:?}: {e}"),
+            ),
+        }
+    }
+
+    // The exact words are also part of the words FST.
+    let words_fst = index.words_fst(&rtxn).context("While reading the words FST")?;
+    for database_name in [db_name::WORD_DOCIDS, db_name::EXACT_WORD_DOCIDS] {
+        let database: Database<Bytes, Bytes> = index_database(&index, database_name)?;
+        for result in database.iter(&rtxn)? {
+            let (key, _) =
+                result.with_context(|| format!("While reading the {database_name} database"))?;
+            // the malformed keys are reported with the other postings below
+            if let Ok(word) = Str::bytes_decode(key) {
+                if !words_fst.contains(word) {
+                    report(database_name, format!("the word {word:?} is not in the words FST"));
+                }
+            }
+        }
+    }
+
+    // The keys decoders, failing on malformed keys.
+    let word = |bytes: &[u8]| Str::bytes_decode(bytes).map(|key| format!("{key:?}"));
+    let word_pair = |bytes: &[u8]| U8StrStrCodec::bytes_decode(bytes).map(|key| format!("{key:?}"));
+    let word_u16 = |bytes: &[u8]| StrBEU16Codec::bytes_decode(bytes).map(|key| format!("{key:?}"));
+    let fid_word_count =
+        |bytes: &[u8]| FieldIdWordCountCodec::bytes_decode(bytes).map(|key| format!("{key:?}"));
+    let fid = |bytes: &[u8]| FieldIdCodec::bytes_decode(bytes).map(|key| format!("{key:?}"));
+    let facet_f64_group = |bytes: &[u8]| {
+        FacetGroupKeyCodec::<OrderedF64Codec>::bytes_decode(bytes).map(|key| format!("{key:?}"))
+    };
+    let facet_string_group = |bytes: &[u8]| {
+        FacetGroupKeyCodec::<StrRefCodec>::bytes_decode(bytes).map(|key| format!("{key:?}"))
+    };
+
+    // The values decoders.
+    let docids = |bytes: &[u8]| CboRoaringBitmapCodec::bytes_decode(bytes);
+    let facet_group = |bytes: &[u8]| {
+        FacetGroupValueCodec::bytes_decode(bytes).map(|FacetGroupValue { bitmap, .. }| bitmap)
+    };
+
+    type Decode<'a, T> = &'a dyn Fn(&[u8]) -> Result<T, BoxedError>;
+    let postings: [(&str, Decode<String>, Decode<RoaringBitmap>); 15] = [
+        (db_name::WORD_DOCIDS, &word, &docids),
+        (db_name::EXACT_WORD_DOCIDS, &word, &docids),
+        (db_name::WORD_PREFIX_DOCIDS, &word, &docids),
+        (db_name::EXACT_WORD_PREFIX_DOCIDS, &word, &docids),
+        (db_name::WORD_PAIR_PROXIMITY_DOCIDS, &word_pair, &docids),
+        (db_name::WORD_POSITION_DOCIDS, &word_u16, &docids),
+        (db_name::WORD_PREFIX_POSITION_DOCIDS, &word_u16, &docids),
+        (db_name::WORD_FIELD_ID_DOCIDS, &word_u16, &docids),
+        (db_name::WORD_PREFIX_FIELD_ID_DOCIDS, &word_u16, &docids),
+        (db_name::FIELD_ID_WORD_COUNT_DOCIDS, &fid_word_count, &docids),
+        (db_name::FACET_ID_EXISTS_DOCIDS, &fid, &docids),
+        (db_name::FACET_ID_IS_NULL_DOCIDS, &fid, &docids),
+        (db_name::FACET_ID_IS_EMPTY_DOCIDS, &fid, &docids),
+        (db_name::FACET_ID_F64_DOCIDS, &facet_f64_group, &facet_group),
+        (db_name::FACET_ID_STRING_DOCIDS, &facet_string_group, &facet_group),
+    ];
+
+    for (database_name, decode_key, decode_docids) in postings {
+        let database: Database<Bytes, Bytes> = index_database(&index, database_name)?;
+        for result in database.iter(&rtxn)? {
+            let (key, value) =
+                result.with_context(|| format!("While reading the {database_name} database"))?;
+            let key = match decode_key(key) {
+                Ok(key) => key,
+                Err(e) => {
+                    report(database_name, format!("malformed key {key:?}: {e}"));
+                    continue;
+                }
+            };
+            match decode_docids(value) {
+                Ok(docids) => {
+                    let unknown = docids - &documents_ids;
+                    if let Some(docid) = unknown.min() {
+                        report(
+                            database_name,
+                            format!(
+                                "{key} references {} unknown documents, e.g. {docid}",
+                                unknown.len()
+                            ),
+                        );
+                    }
+                }
+                Err(e) => report(database_name, format!("the docids of {key} are corrupted: {e}")),
+            }
+        }
+    }
+
+    // The documents facet values are keyed by their document id.
+    let fid_docid_decoders: [(&str, Decode<u32>); 2] = [
+        (db_name::FIELD_ID_DOCID_FACET_F64S, &|bytes: &[u8]| {
+            FieldDocIdFacetF64Codec::bytes_decode(bytes).map(|(_, docid, _)| docid)
+        }),
+        (db_name::FIELD_ID_DOCID_FACET_STRINGS, &|bytes: &[u8]| {
+            FieldDocIdFacetStringCodec::bytes_decode(bytes).map(|(_, docid, _)| docid)
+        }),
+    ];
+    for (database_name, decode_docid) in fid_docid_decoders {
+        let database: Database<Bytes, Bytes> = index_database(&index, database_name)?;
+        for result in database.iter(&rtxn)? {
+            let (key, _) =
+                result.with_context(|| format!("While reading the {database_name} database"))?;
+            match decode_docid(key) {
+                Ok(docid) if !documents_ids.contains(docid) => report(
+                    database_name,
+                    format!("the key {key:?} references the unknown document {docid}"),
+                ),
+                Ok(_) => (),
+                Err(e) => report(database_name, format!("malformed key {key:?}: {e}")),
+            }
+        }
+    }
+
+    if violations > 0 {
+        anyhow::bail!("Found {violations} violations in the index {index_name:?}");
+    }
+    eprintln!("No violation found in the index {index_name:?}");
+
+    Ok(())
+}
+
+/// Compacts an index by copying it without its free pages and replacing the original file.
+fn compact_index(db_path: PathBuf, index_name: String) -> anyhow::Result<()> {
+    let index = open_index(&db_path, &index_name)?;
+    let index_path = index.path().to_path_buf();
+    let index_file_path = index_path.join("data.mdb");
+    let compacted_index_file_path = index_path.join("data.mdb.compacted");
+
+    // A previous run may have been interrupted and left a compacted copy behind.
+    match remove_file(&compacted_index_file_path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => {
+            return Err(e).with_context(|| {
+                format!("While removing the stale {:?}", compacted_index_file_path.display())
+            });
+        }
+        _ => (),
+    }
+
+    eprintln!("Compacting the index...");
+
+    let (before_size, after_size) =
+        match replace_by_compacted_copy(index, &index_file_path, &compacted_index_file_path) {
+            Ok(sizes) => sizes,
+            Err(e) => {
+                // Never leave a partial or unused copy next to the index.
+                let _ = remove_file(&compacted_index_file_path);
+                return Err(e);
+            }
+        };
+
+    eprintln!("Successfully compacted the index from {before_size} bytes to {after_size} bytes!");
+
+    Ok(())
+}
+
+/// Copies the index without its free pages and replaces the index file by this copy.
+///
+/// Returns the size of the index file before and after the compaction.
+fn replace_by_compacted_copy(
+    index: Index,
+    index_file_path: &Path,
+    compacted_index_file_path: &Path,
+) -> anyhow::Result<(u64, u64)> {
+    // We keep a write transaction to make sure nobody writes in the index while it is copied.
+    let wtxn = index.write_txn().context("While waiting for a write transaction")?;
+    let compacted_file =
+        index.copy_to_file(compacted_index_file_path, CompactionOption::Enabled).with_context(
+            || format!("While compacting the index into {:?}", compacted_index_file_path.display()),
+        )?;
+    let before_size = index_file_path.metadata()?.len();
+    let after_size = compacted_file.metadata()?.len();
+    drop(compacted_file);
+
+    wtxn.abort();
+    index.prepare_for_closing().wait();
+
+    rename(compacted_index_file_path, index_file_path).with_context(|| {
+        format!("While replacing {:?} by the compacted index", index_file_path.display())
+    })?;
+
+    Ok((before_size, after_size))
+}
+
+/// Prints the `limit` entries with the biggest values of the word databases of an index.
+fn index_heaviest_keys(db_path: PathBuf, index_name: String, limit: usize) -> anyhow::Result<()> {
+    let index = open_index_read_only(&db_path, &index_name)?;
+    let rtxn = index.read_txn()?;
+
+    // A min-heap that only keeps the `limit` heaviest entries seen so far.
+    let mut heaviest = BinaryHeap::new();
+
+    let word_docids: Database<Str, Bytes> = index_database(&index, db_name::WORD_DOCIDS)?;
+    for result in word_docids.iter(&rtxn)? {
+        let (word, value) = result.context("While reading the word docids database")?;
+        push_if_heavier(&mut heaviest, limit, value.len(), db_name::WORD_DOCIDS, || {
+            word.to_string()
+        });
+    }
+
+    let word_pair_proximity_docids: Database<U8StrStrCodec, Bytes> =
+        index_database(&index, db_name::WORD_PAIR_PROXIMITY_DOCIDS)?;
+    for result in word_pair_proximity_docids.iter(&rtxn)? {
+        let ((proximity, left, right), value) =
+            result.context("While reading the word pair proximity docids database")?;
+        push_if_heavier(
+            &mut heaviest,
+            limit,
+            value.len(),
+            db_name::WORD_PAIR_PROXIMITY_DOCIDS,
+            || format!("{left} {right} (proximity {proximity})"),
+        );
+    }
+
+    let word_position_docids: Database<StrBEU16Codec, Bytes> =
+        index_database(&index, db_name::WORD_POSITION_DOCIDS)?;
+    for result in word_position_docids.iter(&rtxn)? {
+        let ((word, position), value) =
+            result.context("While reading the word position docids database")?;
+        push_if_heavier(&mut heaviest, limit, value.len(), db_name::WORD_POSITION_DOCIDS, || {
+            format!("{word} (position {position})")
+        });
+    }
+
+    for Reverse((size, database, key)) in heaviest.into_sorted_vec() {
+        println!("{size}\t{database}\t{key}");
+    }
+
+    Ok(())
+}
+
+/// Pushes an entry in the heap and removes the lightest one if there are more than `limit` of them.
+///
+/// The key is only formatted if the entry is heavy enough to be kept.
+fn push_if_heavier(
+    heap: &mut BinaryHeap<Reverse<(usize, &'static str, String)>>,
+    limit: usize,
+    size: usize,
+    database: &'static str,
+    key: impl FnOnce() -> String,
+) {
+    if heap.len() >= limit
+        && heap.peek().map_or(true, |Reverse((lightest, _, _))| size <= *lightest)
+    {
+        return;
+    }
+
+    heap.push(Reverse((size, database, key())));
+    if heap.len() > limit {
+        heap.pop();
+    }
+}
+
 fn try_opening_database<KC: 'static, DC: 'static>(
     env: &Env,
     rtxn: &RoTxn,
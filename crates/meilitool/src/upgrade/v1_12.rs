@@ -0,0 +1,75 @@
+//! The breaking changes that happened between the v1.11 and the v1.12 are:
+//! - The words longer than the maximum LMDB key size are truncated instead of being dropped.
+//!   The word databases of the existing indexes don't contain these words and the word counts
+//!   of their documents ignore them => We reindex the searchable fields so that the deletions
+//!   computed by the next document updates match what is stored.
+
+use std::path::Path;
+
+use anyhow::Context;
+use meilisearch_types::heed::types::Str;
+use meilisearch_types::heed::{Database, EnvOpenOptions};
+use meilisearch_types::milli::update::{IndexerConfig, Settings};
+use meilisearch_types::milli::Index;
+
+use crate::try_opening_database;
+use crate::uuid_codec::UuidCodec;
+
+/// The default maximum size of an index in Meilisearch, the reindexing may grow the index.
+const INDEX_MAP_SIZE: usize = 2 * 1024 * 1024 * 1024 * 1024;
+
+pub fn v1_11_to_v1_12(db_path: &Path) -> anyhow::Result<()> {
+    println!("Upgrading from v1.11.0 to v1.12.0");
+
+    let index_scheduler_path = db_path.join("tasks");
+    let env = unsafe { EnvOpenOptions::new().max_dbs(100).open(&index_scheduler_path) }
+        .with_context(|| format!("While trying to open {:?}", index_scheduler_path.display()))?;
+
+    let sched_rtxn = env.read_txn()?;
+
+    let index_mapping: Database<Str, UuidCodec> =
+        try_opening_database(&env, &sched_rtxn, "index-mapping")?;
+
+    let index_count =
+        index_mapping.len(&sched_rtxn).context("while reading the number of indexes")?;
+
+    let indexes: Vec<_> = index_mapping
+        .iter(&sched_rtxn)?
+        .map(|res| res.map(|(uid, uuid)| (uid.to_owned(), uuid)))
+        .collect();
+
+    let indexer_config = IndexerConfig::default();
+
+    for (index_index, result) in indexes.into_iter().enumerate() {
+        let (uid, uuid) = result?;
+        let index_path = db_path.join("indexes").join(uuid.to_string());
+
+        println!(
+            "[{}/{index_count}]Reindexing the searchable fields of `{uid}` at `{}`",
+            index_index + 1,
+            index_path.display()
+        );
+
+        let mut options = EnvOpenOptions::new();
+        options.map_size(INDEX_MAP_SIZE);
+        let index = Index::new(options, &index_path)
+            .with_context(|| format!("while opening index {uid} at '{}'", index_path.display()))?;
+
+        let mut index_wtxn = index.write_txn().with_context(|| {
+            format!(
+                "while obtaining a write transaction for index {uid} at {}",
+                index_path.display()
+            )
+        })?;
+
+        let mut settings = Settings::new(&mut index_wtxn, &index, &indexer_config);
+        settings.set_truncate_long_words(true);
+        settings
+            .execute(|_| (), || false)
+            .with_context(|| format!("while reindexing the searchable fields of `{uid}`"))?;
+
+        index_wtxn.commit()?;
+    }
+
+    Ok(())
+}
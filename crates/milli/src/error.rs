@@ -33,6 +33,8 @@ pub enum InternalError {
     DatabaseClosing,
     #[error("missing {} in the {db_name} database", key.unwrap_or("key"))]
     DatabaseMissingEntry { db_name: &'static str, key: Option<&'static str> },
+    #[error("missing the {db_name} database")]
+    MissingDatabase { db_name: &'static str },
     #[error("missing {key} in the fieldids weights mapping")]
     FieldidsWeightsMapMissingEntry { key: FieldId },
     #[error(transparent)]
@@ -49,6 +51,8 @@ pub enum InternalError {
     GrenadInvalidFormatVersion,
     #[error("invalid merge while processing {process}")]
     IndexingMergingKeys { process: &'static str },
+    #[error("invalid value for the key {} while merging in the {process} process", String::from_utf8_lossy(.key))]
+    IndexingMergingValue { process: &'static str, key: Vec<u8> },
     #[error(transparent)]
     RayonThreadPool(#[from] ThreadPoolBuildError),
     #[error(transparent)]
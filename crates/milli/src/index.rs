@@ -6,6 +6,7 @@ use std::path::Path;
 
 use heed::types::*;
 use heed::{CompactionOption, Database, RoTxn, RwTxn, Unspecified};
+use indexmap::IndexMap;
 use roaring::RoaringBitmap;
 use rstar::RTree;
 use serde::{Deserialize, Serialize};
@@ -20,13 +21,15 @@ use crate::heed_codec::facet::{
 use crate::heed_codec::{BEU16StrCodec, FstSetCodec, StrBEU16Codec, StrRefCodec};
 use crate::order_by_map::OrderByMap;
 use crate::proximity::ProximityPrecision;
+use crate::search::new::{find_typo_corrections, number_of_typos_allowed_in_index};
 use crate::vector::parsed_vectors::RESERVED_VECTORS_FIELD_NAME;
 use crate::vector::{ArroyWrapper, Embedding, EmbeddingConfig};
 use crate::{
-    default_criteria, CboRoaringBitmapCodec, Criterion, DocumentId, ExternalDocumentsIds,
-    FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldIdWordCountCodec,
-    FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule, ObkvCodec, Result, RoaringBitmapCodec,
-    RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32, BEU64,
+    default_criteria, CboRoaringBitmapCodec, CboRoaringBitmapLenCodec, Criterion, DocumentId,
+    ExternalDocumentsIds, FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry,
+    FieldIdWordCountCodec, FieldidsWeightsMap, GeoPoint, LocalizedAttributesRule, ObkvCodec,
+    Result, RoaringBitmapCodec, RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32,
+    BEU64,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
@@ -70,6 +73,7 @@ pub mod main_key {
     pub const EMBEDDING_CONFIGS: &str = "embedding_configs";
     pub const SEARCH_CUTOFF: &str = "search_cutoff";
     pub const LOCALIZED_ATTRIBUTES_RULES: &str = "localized_attributes_rules";
+    pub const TRUNCATE_LONG_WORDS: &str = "truncate-long-words";
 }
 
 pub mod db_name {
@@ -100,6 +104,11 @@ pub mod db_name {
     pub const DOCUMENTS: &str = "documents";
 }
 
+/// An index stored in a LMDB environment.
+///
+/// Cloning an index is cheap, all the clones share the same environment. Read transactions
+/// can be opened from any thread, even while a write transaction is in flight, and always
+/// see the state of the index at the time of the last commit.
 #[derive(Clone)]
 pub struct Index {
     /// The LMDB environment which this index is associated with.
@@ -175,83 +184,18 @@ impl Index {
         created_at: time::OffsetDateTime,
         updated_at: time::OffsetDateTime,
     ) -> Result<Index> {
-        use db_name::*;
-
         options.max_dbs(25);
 
         let env = unsafe { options.open(path) }?;
         let mut wtxn = env.write_txn()?;
-        let main = env.database_options().name(MAIN).create(&mut wtxn)?;
-        let word_docids = env.create_database(&mut wtxn, Some(WORD_DOCIDS))?;
-        let external_documents_ids =
-            env.create_database(&mut wtxn, Some(EXTERNAL_DOCUMENTS_IDS))?;
-        let exact_word_docids = env.create_database(&mut wtxn, Some(EXACT_WORD_DOCIDS))?;
-        let word_prefix_docids = env.create_database(&mut wtxn, Some(WORD_PREFIX_DOCIDS))?;
-        let exact_word_prefix_docids =
-            env.create_database(&mut wtxn, Some(EXACT_WORD_PREFIX_DOCIDS))?;
-        let word_pair_proximity_docids =
-            env.create_database(&mut wtxn, Some(WORD_PAIR_PROXIMITY_DOCIDS))?;
-        let word_position_docids = env.create_database(&mut wtxn, Some(WORD_POSITION_DOCIDS))?;
-        let word_fid_docids = env.create_database(&mut wtxn, Some(WORD_FIELD_ID_DOCIDS))?;
-        let field_id_word_count_docids =
-            env.create_database(&mut wtxn, Some(FIELD_ID_WORD_COUNT_DOCIDS))?;
-        let word_prefix_position_docids =
-            env.create_database(&mut wtxn, Some(WORD_PREFIX_POSITION_DOCIDS))?;
-        let word_prefix_fid_docids =
-            env.create_database(&mut wtxn, Some(WORD_PREFIX_FIELD_ID_DOCIDS))?;
-        let facet_id_f64_docids = env.create_database(&mut wtxn, Some(FACET_ID_F64_DOCIDS))?;
-        let facet_id_string_docids =
-            env.create_database(&mut wtxn, Some(FACET_ID_STRING_DOCIDS))?;
-        let facet_id_normalized_string_strings =
-            env.create_database(&mut wtxn, Some(FACET_ID_NORMALIZED_STRING_STRINGS))?;
-        let facet_id_string_fst = env.create_database(&mut wtxn, Some(FACET_ID_STRING_FST))?;
-        let facet_id_exists_docids =
-            env.create_database(&mut wtxn, Some(FACET_ID_EXISTS_DOCIDS))?;
-        let facet_id_is_null_docids =
-            env.create_database(&mut wtxn, Some(FACET_ID_IS_NULL_DOCIDS))?;
-        let facet_id_is_empty_docids =
-            env.create_database(&mut wtxn, Some(FACET_ID_IS_EMPTY_DOCIDS))?;
-        let field_id_docid_facet_f64s =
-            env.create_database(&mut wtxn, Some(FIELD_ID_DOCID_FACET_F64S))?;
-        let field_id_docid_facet_strings =
-            env.create_database(&mut wtxn, Some(FIELD_ID_DOCID_FACET_STRINGS))?;
-        // vector stuff
-        let embedder_category_id =
-            env.create_database(&mut wtxn, Some(VECTOR_EMBEDDER_CATEGORY_ID))?;
-        let vector_arroy = env.create_database(&mut wtxn, Some(VECTOR_ARROY))?;
-
-        let documents = env.create_database(&mut wtxn, Some(DOCUMENTS))?;
+        let index = Self::from_databases(env.clone(), |name| {
+            env.create_database(&mut wtxn, Some(name)).map_err(Into::into)
+        })?;
         wtxn.commit()?;
 
-        Index::set_creation_dates(&env, main, created_at, updated_at)?;
+        Index::set_creation_dates(&index.env, index.main, created_at, updated_at)?;
 
-        Ok(Index {
-            env,
-            main,
-            external_documents_ids,
-            word_docids,
-            exact_word_docids,
-            word_prefix_docids,
-            exact_word_prefix_docids,
-            word_pair_proximity_docids,
-            word_position_docids,
-            word_fid_docids,
-            word_prefix_position_docids,
-            word_prefix_fid_docids,
-            field_id_word_count_docids,
-            facet_id_f64_docids,
-            facet_id_string_docids,
-            facet_id_normalized_string_strings,
-            facet_id_string_fst,
-            facet_id_exists_docids,
-            facet_id_is_null_docids,
-            facet_id_is_empty_docids,
-            field_id_docid_facet_f64s,
-            field_id_docid_facet_strings,
-            vector_arroy,
-            embedder_category_id,
-            documents,
-        })
+        Ok(index)
     }
 
     pub fn new<P: AsRef<Path>>(options: heed::EnvOpenOptions, path: P) -> Result<Index> {
@@ -259,6 +203,77 @@ impl Index {
         Self::new_with_creation_dates(options, path, now, now)
     }
 
+    /// Opens an existing index without ever writing into it.
+    ///
+    /// The environment is opened with the LMDB read-only flag so that an index can be served
+    /// from a read-only directory, the returned index only exposes read operations.
+    pub fn open_read_only<P: AsRef<Path>>(
+        mut options: heed::EnvOpenOptions,
+        path: P,
+    ) -> Result<ReadOnlyIndex> {
+        options.max_dbs(25);
+        unsafe { options.flags(heed::EnvFlags::READ_ONLY) };
+
+        let env = unsafe { options.open(path) }?;
+        let rtxn = env.read_txn()?;
+        let index =
+            Self::from_databases(env.clone(), |name| Self::open_database(&env, &rtxn, name))?;
+        // The databases opened in a read transaction are only kept once it is committed.
+        rtxn.commit()?;
+
+        Ok(ReadOnlyIndex(index))
+    }
+
+    /// Builds an index from the databases returned by `open` for each database name.
+    ///
+    /// This is where the list of the databases of an index lives, both when
+    /// creating the databases and when opening them read-only.
+    fn from_databases(
+        env: heed::Env,
+        mut open: impl FnMut(&'static str) -> Result<Database<Unspecified, Unspecified>>,
+    ) -> Result<Index> {
+        use db_name::*;
+
+        Ok(Index {
+            main: open(MAIN)?,
+            external_documents_ids: open(EXTERNAL_DOCUMENTS_IDS)?.remap_types(),
+            word_docids: open(WORD_DOCIDS)?.remap_types(),
+            exact_word_docids: open(EXACT_WORD_DOCIDS)?.remap_types(),
+            word_prefix_docids: open(WORD_PREFIX_DOCIDS)?.remap_types(),
+            exact_word_prefix_docids: open(EXACT_WORD_PREFIX_DOCIDS)?.remap_types(),
+            word_pair_proximity_docids: open(WORD_PAIR_PROXIMITY_DOCIDS)?.remap_types(),
+            word_position_docids: open(WORD_POSITION_DOCIDS)?.remap_types(),
+            word_fid_docids: open(WORD_FIELD_ID_DOCIDS)?.remap_types(),
+            field_id_word_count_docids: open(FIELD_ID_WORD_COUNT_DOCIDS)?.remap_types(),
+            word_prefix_position_docids: open(WORD_PREFIX_POSITION_DOCIDS)?.remap_types(),
+            word_prefix_fid_docids: open(WORD_PREFIX_FIELD_ID_DOCIDS)?.remap_types(),
+            facet_id_f64_docids: open(FACET_ID_F64_DOCIDS)?.remap_types(),
+            facet_id_string_docids: open(FACET_ID_STRING_DOCIDS)?.remap_types(),
+            facet_id_normalized_string_strings: open(FACET_ID_NORMALIZED_STRING_STRINGS)?
+                .remap_types(),
+            facet_id_string_fst: open(FACET_ID_STRING_FST)?.remap_types(),
+            facet_id_exists_docids: open(FACET_ID_EXISTS_DOCIDS)?.remap_types(),
+            facet_id_is_null_docids: open(FACET_ID_IS_NULL_DOCIDS)?.remap_types(),
+            facet_id_is_empty_docids: open(FACET_ID_IS_EMPTY_DOCIDS)?.remap_types(),
+            field_id_docid_facet_f64s: open(FIELD_ID_DOCID_FACET_F64S)?.remap_types(),
+            field_id_docid_facet_strings: open(FIELD_ID_DOCID_FACET_STRINGS)?.remap_types(),
+            // vector stuff
+            embedder_category_id: open(VECTOR_EMBEDDER_CATEGORY_ID)?.remap_types(),
+            vector_arroy: open(VECTOR_ARROY)?.remap_types(),
+            documents: open(DOCUMENTS)?.remap_types(),
+            env,
+        })
+    }
+
+    fn open_database<KC: 'static, DC: 'static>(
+        env: &heed::Env,
+        rtxn: &RoTxn<'_>,
+        db_name: &'static str,
+    ) -> Result<Database<KC, DC>> {
+        env.open_database(rtxn, Some(db_name))?
+            .ok_or_else(|| InternalError::MissingDatabase { db_name }.into())
+    }
+
     fn set_creation_dates(
         env: &heed::Env,
         main: Database<Unspecified, Unspecified>,
@@ -271,6 +286,9 @@ impl Index {
         if main.get(&txn, main_key::CREATED_AT_KEY)?.is_none() {
             main.put(&mut txn, main_key::UPDATED_AT_KEY, &OffsetDateTime(updated_at))?;
             main.put(&mut txn, main_key::CREATED_AT_KEY, &OffsetDateTime(created_at))?;
+            // New indexes truncate the long words from the start, the older ones keep
+            // dropping them until they are reindexed by the offline upgrade.
+            main.remap_data_type::<U8>().put(&mut txn, main_key::TRUNCATE_LONG_WORDS, &1)?;
             txn.commit()?;
         }
         Ok(())
@@ -320,6 +338,119 @@ impl Index {
         self.env.copy_to_file(path, option).map_err(Into::into)
     }
 
+    /// Returns the LMDB statistics of each of the databases of this index, by database name.
+    ///
+    /// They are maintained by LMDB, reading them doesn't depend on the size of the databases.
+    pub fn database_stats(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<IndexMap<&'static str, DatabaseStats>> {
+        self.all_databases()
+            .into_iter()
+            .map(|(name, database)| {
+                let heed::DatabaseStat {
+                    page_size,
+                    depth,
+                    branch_pages,
+                    leaf_pages,
+                    overflow_pages,
+                    entries,
+                } = database.stat(rtxn)?;
+                let stats = DatabaseStats {
+                    number_of_entries: entries as u64,
+                    depth,
+                    page_size,
+                    branch_pages: branch_pages as u64,
+                    leaf_pages: leaf_pages as u64,
+                    overflow_pages: overflow_pages as u64,
+                };
+                Ok((name, stats))
+            })
+            .collect()
+    }
+
+    /// Returns the sum of the sizes of the keys and values of each of the databases of this
+    /// index, by database name.
+    ///
+    /// Every entry of the index is read, the sizes don't take the LMDB pages overhead into account.
+    pub fn database_sizes(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<IndexMap<&'static str, DatabaseSizes>> {
+        self.all_databases()
+            .into_iter()
+            .map(|(name, database)| {
+                let mut sizes = DatabaseSizes::default();
+                for result in database.iter(rtxn)? {
+                    let (key, value) = result?;
+                    sizes.total_key_size += key.len() as u64;
+                    sizes.total_value_size += value.len() as u64;
+                }
+                Ok((name, sizes))
+            })
+            .collect()
+    }
+
+    /// Returns all the databases of this index, by database name.
+    fn all_databases(&self) -> [(&'static str, Database<Bytes, Bytes>); 24] {
+        use db_name::*;
+
+        let Self {
+            env: _,
+            main,
+            external_documents_ids,
+            word_docids,
+            exact_word_docids,
+            word_prefix_docids,
+            exact_word_prefix_docids,
+            word_pair_proximity_docids,
+            word_position_docids,
+            word_fid_docids,
+            field_id_word_count_docids,
+            word_prefix_position_docids,
+            word_prefix_fid_docids,
+            facet_id_exists_docids,
+            facet_id_is_null_docids,
+            facet_id_is_empty_docids,
+            facet_id_f64_docids,
+            facet_id_string_docids,
+            facet_id_normalized_string_strings,
+            facet_id_string_fst,
+            field_id_docid_facet_f64s,
+            field_id_docid_facet_strings,
+            embedder_category_id,
+            vector_arroy,
+            documents,
+        } = self;
+
+        [
+            (MAIN, main.remap_types()),
+            (EXTERNAL_DOCUMENTS_IDS, external_documents_ids.remap_types()),
+            (WORD_DOCIDS, word_docids.remap_types()),
+            (EXACT_WORD_DOCIDS, exact_word_docids.remap_types()),
+            (WORD_PREFIX_DOCIDS, word_prefix_docids.remap_types()),
+            (EXACT_WORD_PREFIX_DOCIDS, exact_word_prefix_docids.remap_types()),
+            (WORD_PAIR_PROXIMITY_DOCIDS, word_pair_proximity_docids.remap_types()),
+            (WORD_POSITION_DOCIDS, word_position_docids.remap_types()),
+            (WORD_FIELD_ID_DOCIDS, word_fid_docids.remap_types()),
+            (FIELD_ID_WORD_COUNT_DOCIDS, field_id_word_count_docids.remap_types()),
+            (WORD_PREFIX_POSITION_DOCIDS, word_prefix_position_docids.remap_types()),
+            (WORD_PREFIX_FIELD_ID_DOCIDS, word_prefix_fid_docids.remap_types()),
+            (FACET_ID_EXISTS_DOCIDS, facet_id_exists_docids.remap_types()),
+            (FACET_ID_IS_NULL_DOCIDS, facet_id_is_null_docids.remap_types()),
+            (FACET_ID_IS_EMPTY_DOCIDS, facet_id_is_empty_docids.remap_types()),
+            (FACET_ID_F64_DOCIDS, facet_id_f64_docids.remap_types()),
+            (FACET_ID_STRING_DOCIDS, facet_id_string_docids.remap_types()),
+            (FACET_ID_NORMALIZED_STRING_STRINGS, facet_id_normalized_string_strings.remap_types()),
+            (FACET_ID_STRING_FST, facet_id_string_fst.remap_types()),
+            (FIELD_ID_DOCID_FACET_F64S, field_id_docid_facet_f64s.remap_types()),
+            (FIELD_ID_DOCID_FACET_STRINGS, field_id_docid_facet_strings.remap_types()),
+            (VECTOR_EMBEDDER_CATEGORY_ID, embedder_category_id.remap_types()),
+            (VECTOR_ARROY, vector_arroy.remap_types()),
+            (DOCUMENTS, documents.remap_types()),
+        ]
+    }
+
     /// Returns an `EnvClosingEvent` that can be used to wait for the closing event,
     /// multiple threads can wait on this event.
     ///
@@ -1246,7 +1377,36 @@ impl Index {
     /// Returns the number of documents ids associated with the given word,
     /// it is much faster than deserializing the bitmap and getting the length of it.
     pub fn word_documents_count(&self, rtxn: &RoTxn<'_>, word: &str) -> heed::Result<Option<u64>> {
-        self.word_docids.remap_data_type::<RoaringBitmapLenCodec>().get(rtxn, word)
+        self.word_docids.remap_data_type::<CboRoaringBitmapLenCodec>().get(rtxn, word)
+    }
+
+    /// Returns the words of the index that are one or two typos away from the given word,
+    /// along with the number of documents containing them.
+    ///
+    /// The number of typos allowed follows the typo tolerance settings of the index and
+    /// a typo on the first letter counts as two typos, the same way it is computed for
+    /// the words of a search query.
+    ///
+    /// Like for the query words, at most 150 one-typo and 50 two-typos corrections are returned.
+    /// The corrections are sorted by number of typos and then by decreasing number of documents.
+    /// The given word must be normalized the same way the indexed words are, e.g. lowercased.
+    pub fn correct(&self, rtxn: &RoTxn<'_>, word: &str) -> Result<Vec<(String, u64)>> {
+        let max_typos = number_of_typos_allowed_in_index(self, rtxn)?(word);
+        if max_typos == 0 {
+            return Ok(Vec::new());
+        }
+
+        let words_fst = self.words_fst(rtxn)?;
+        let mut corrections = Vec::new();
+        for (derived_word, typos) in find_typo_corrections(&words_fst, word, max_typos)? {
+            let count = self.word_documents_count(rtxn, &derived_word)?.unwrap_or_default();
+            corrections.push((typos, derived_word, count));
+        }
+
+        corrections.sort_by(|(ltypos, _, lcount), (rtypos, _, rcount)| {
+            ltypos.cmp(rtypos).then_with(|| rcount.cmp(lcount))
+        });
+        Ok(corrections.into_iter().map(|(_, word, count)| (word, count)).collect())
     }
 
     /* documents */
@@ -1386,6 +1546,31 @@ impl Index {
         Ok(())
     }
 
+    /// Whether the words longer than [`crate::MAX_WORD_LENGTH`] are truncated or dropped.
+    ///
+    /// The absence of a value is false, because the indexes created before this setting
+    /// existed dropped them and their word databases must stay consistent with that.
+    pub fn truncate_long_words(&self, txn: &RoTxn<'_>) -> heed::Result<bool> {
+        match self.main.remap_types::<Str, U8>().get(txn, main_key::TRUNCATE_LONG_WORDS)? {
+            Some(0) | None => Ok(false),
+            _ => Ok(true),
+        }
+    }
+
+    pub(crate) fn put_truncate_long_words(
+        &self,
+        txn: &mut RwTxn<'_>,
+        flag: bool,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, U8>().put(
+            txn,
+            main_key::TRUNCATE_LONG_WORDS,
+            &(flag as u8),
+        )?;
+
+        Ok(())
+    }
+
     pub fn min_word_len_one_typo(&self, txn: &RoTxn<'_>) -> heed::Result<u8> {
         // It is not possible to put a bool in heed with OwnedType, so we put a u8 instead. We
         // identify 0 as being false, and anything else as true. The absence of a value is true,
@@ -1670,24 +1855,155 @@ pub struct PrefixSettings {
     pub compute_prefixes: bool,
 }
 
+/// Statistics about one of the LMDB databases of an index.
+/// An index opened with [`Index::open_read_only`].
+///
+/// It never takes the LMDB writer lock and only exposes the read operations of an [`Index`].
+#[derive(Clone)]
+pub struct ReadOnlyIndex(Index);
+
+impl ReadOnlyIndex {
+    /// Create a read transaction to be able to read the index.
+    pub fn read_txn(&self) -> heed::Result<RoTxn<'_>> {
+        self.0.read_txn()
+    }
+
+    /// Returns the canonicalized path where the heed `Env` of this `Index` lives.
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
+    /// Returns an `EnvClosingEvent` that can be used to wait for the closing event.
+    pub fn prepare_for_closing(self) -> heed::EnvClosingEvent {
+        self.0.prepare_for_closing()
+    }
+
+    /// Returns the database with the given name, its keys and values are left undecoded.
+    pub fn database(&self, name: &str) -> Option<Database<Bytes, Bytes>> {
+        self.0
+            .all_databases()
+            .into_iter()
+            .find_map(|(db_name, database)| (db_name == name).then_some(database))
+    }
+
+    pub fn database_stats(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<IndexMap<&'static str, DatabaseStats>> {
+        self.0.database_stats(rtxn)
+    }
+
+    pub fn database_sizes(
+        &self,
+        rtxn: &RoTxn<'_>,
+    ) -> heed::Result<IndexMap<&'static str, DatabaseSizes>> {
+        self.0.database_sizes(rtxn)
+    }
+
+    pub fn documents_ids(&self, rtxn: &RoTxn<'_>) -> heed::Result<RoaringBitmap> {
+        self.0.documents_ids(rtxn)
+    }
+
+    pub fn number_of_documents(&self, rtxn: &RoTxn<'_>) -> Result<u64> {
+        self.0.number_of_documents(rtxn)
+    }
+
+    pub fn primary_key<'t>(&self, rtxn: &'t RoTxn<'_>) -> heed::Result<Option<&'t str>> {
+        self.0.primary_key(rtxn)
+    }
+
+    pub fn external_documents_ids(&self) -> ExternalDocumentsIds {
+        self.0.external_documents_ids()
+    }
+
+    pub fn fields_ids_map(&self, rtxn: &RoTxn<'_>) -> heed::Result<FieldsIdsMap> {
+        self.0.fields_ids_map(rtxn)
+    }
+
+    pub fn words_fst<'t>(&self, rtxn: &'t RoTxn<'_>) -> Result<fst::Set<Cow<'t, [u8]>>> {
+        self.0.words_fst(rtxn)
+    }
+
+    pub fn word_documents_count(&self, rtxn: &RoTxn<'_>, word: &str) -> heed::Result<Option<u64>> {
+        self.0.word_documents_count(rtxn, word)
+    }
+
+    pub fn correct(&self, rtxn: &RoTxn<'_>, word: &str) -> Result<Vec<(String, u64)>> {
+        self.0.correct(rtxn, word)
+    }
+
+    pub fn document<'t>(&self, rtxn: &'t RoTxn, id: DocumentId) -> Result<&'t obkv::KvReaderU16> {
+        self.0.document(rtxn, id)
+    }
+
+    pub fn all_documents<'a, 't: 'a>(
+        &'a self,
+        rtxn: &'t RoTxn<'t>,
+    ) -> Result<impl Iterator<Item = Result<(DocumentId, &'t obkv::KvReaderU16)>> + 'a> {
+        self.0.all_documents(rtxn)
+    }
+
+    pub fn search<'a>(&'a self, rtxn: &'a RoTxn<'a>) -> Search<'a> {
+        self.0.search(rtxn)
+    }
+
+    pub fn created_at(&self, rtxn: &RoTxn<'_>) -> Result<time::OffsetDateTime> {
+        self.0.created_at(rtxn)
+    }
+
+    pub fn updated_at(&self, rtxn: &RoTxn<'_>) -> Result<time::OffsetDateTime> {
+        self.0.updated_at(rtxn)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseStats {
+    pub number_of_entries: u64,
+    /// The depth of the B-tree.
+    pub depth: u32,
+    /// The size of a page, in bytes.
+    pub page_size: u32,
+    pub branch_pages: u64,
+    pub leaf_pages: u64,
+    /// The pages storing the values that don't fit in a leaf page.
+    pub overflow_pages: u64,
+}
+
+impl DatabaseStats {
+    /// The size taken by the pages of the database, in bytes.
+    pub fn used_size(&self) -> u64 {
+        (self.branch_pages + self.leaf_pages + self.overflow_pages) * self.page_size as u64
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseSizes {
+    /// The sum of the lengths of the keys, in bytes.
+    pub total_key_size: u64,
+    /// The sum of the lengths of the values, in bytes.
+    pub total_value_size: u64,
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(transparent)]
 struct OffsetDateTime(#[serde(with = "time::serde::rfc3339")] time::OffsetDateTime);
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use std::collections::HashSet;
+    use std::collections::{BTreeSet, HashSet};
     use std::ops::Deref;
 
     use big_s::S;
     use bumpalo::Bump;
     use heed::{EnvOpenOptions, RwTxn};
-    use maplit::{btreemap, hashset};
+    use maplit::{btreemap, btreeset, hashset};
     use memmap2::Mmap;
     use tempfile::TempDir;
 
     use crate::error::{Error, InternalError};
-    use crate::index::{DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
+    use crate::index::{db_name, DEFAULT_MIN_WORD_LEN_ONE_TYPO, DEFAULT_MIN_WORD_LEN_TWO_TYPOS};
     use crate::update::new::indexer;
     use crate::update::settings::InnerIndexSettings;
     use crate::update::{
@@ -3052,4 +3368,225 @@ pub(crate) mod tests {
             .unwrap();
         assert!(results.candidates.is_empty());
     }
+
+    #[test]
+    fn word_documents_count() {
+        let index = TempIndex::new();
+
+        // `hello` appears in few enough documents to be stored as a raw list of ids,
+        // while `world` appears in enough documents to be stored as a roaring bitmap.
+        let documents: Vec<_> = (0..20)
+            .map(|i| {
+                let text = if i < 3 { "hello world" } else { "world" };
+                serde_json::json!({ "id": i, "text": text })
+            })
+            .collect();
+        index.add_documents(documents!(documents)).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.word_documents_count(&rtxn, "hello").unwrap(), Some(3));
+        assert_eq!(index.word_documents_count(&rtxn, "world").unwrap(), Some(20));
+        assert_eq!(index.word_documents_count(&rtxn, "missing").unwrap(), None);
+    }
+
+    #[test]
+    fn database_stats() {
+        let index = TempIndex::new();
+        // the primary key values must not be indexed as words
+        index
+            .update_settings(|settings| {
+                settings.set_searchable_fields(vec![S("text")]);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "hello world" },
+                { "id": 1, "text": "hello kevin" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let stats = index.database_stats(&rtxn).unwrap();
+        assert_eq!(stats.len(), 24);
+        assert_eq!(stats[db_name::DOCUMENTS].number_of_entries, 2);
+        assert_eq!(stats[db_name::EXTERNAL_DOCUMENTS_IDS].number_of_entries, 2);
+        assert_eq!(stats[db_name::WORD_DOCIDS].number_of_entries, 3);
+        assert_eq!(stats[db_name::WORD_DOCIDS].leaf_pages, 1);
+        assert_eq!(stats[db_name::WORD_DOCIDS].overflow_pages, 0);
+        assert_eq!(stats[db_name::VECTOR_EMBEDDER_CATEGORY_ID].number_of_entries, 0);
+        assert_eq!(stats[db_name::VECTOR_EMBEDDER_CATEGORY_ID].used_size(), 0);
+
+        let sizes = index.database_sizes(&rtxn).unwrap();
+        assert_eq!(sizes.len(), 24);
+        // the keys are `hello`, `kevin` and `world`
+        assert_eq!(sizes[db_name::WORD_DOCIDS].total_key_size, 15);
+        // an external document id is stored as a 4 bytes document id
+        assert_eq!(sizes[db_name::EXTERNAL_DOCUMENTS_IDS].total_value_size, 8);
+    }
+
+    #[test]
+    fn correct() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_min_word_len_one_typo(4);
+                settings.set_min_word_len_two_typos(4);
+            })
+            .unwrap();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "hello world" },
+                { "id": 1, "text": "hello" },
+                { "id": 2, "text": "hallo" },
+                { "id": 3, "text": "help" },
+                { "id": 4, "text": "yellow" },
+            ]))
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let corrections = index.correct(&rtxn, "helo").unwrap();
+        insta::assert_debug_snapshot!(corrections, @r###"
+        [
+            (
+                "hello",
+                2,
+            ),
+            (
+                "help",
+                1,
+            ),
+            (
+                "hallo",
+                1,
+            ),
+        ]
+        "###);
+
+        // an existing word is not corrected into itself
+        let corrections = index.correct(&rtxn, "world").unwrap();
+        assert!(corrections.is_empty());
+    }
+
+    #[test]
+    fn correct_follows_the_typo_tolerance_settings() {
+        let index = TempIndex::new();
+        index
+            .add_documents(documents!([
+                { "id": 0, "text": "hello" },
+                { "id": 1, "text": "hallo" },
+                { "id": 2, "text": "yellow" },
+            ]))
+            .unwrap();
+
+        // `helo` is too short to contain a typo with the default settings
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.correct(&rtxn, "helo").unwrap().is_empty());
+
+        // `hellow` is long enough for one typo only,
+        // and `yellow` costs two typos as the first letter differs
+        let corrections = index.correct(&rtxn, "hellow").unwrap();
+        let words: Vec<_> = corrections.iter().map(|(word, _)| word.as_str()).collect();
+        assert_eq!(words, ["hello"]);
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.set_exact_words(btreeset! { S("hellow") });
+            })
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.correct(&rtxn, "hellow").unwrap().is_empty());
+        drop(rtxn);
+
+        index
+            .update_settings(|settings| {
+                settings.reset_exact_words();
+                settings.set_autorize_typos(false);
+            })
+            .unwrap();
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.correct(&rtxn, "hellow").unwrap().is_empty());
+    }
+
+    #[test]
+    fn correct_caps_the_number_of_corrections() {
+        let index = TempIndex::new();
+
+        // more than 150 words are one typo away from `hello`
+        let mut words = BTreeSet::new();
+        for c in 'a'..='z' {
+            for position in 1..=5 {
+                let mut word = S("hello");
+                word.insert(position, c);
+                words.insert(word);
+            }
+            for position in 1..5 {
+                let mut word = S("hello");
+                word.replace_range(position..=position, c.encode_utf8(&mut [0; 4]));
+                words.insert(word);
+            }
+        }
+        let text = words.into_iter().collect::<Vec<_>>().join(" ");
+        index.add_documents(documents!([{ "id": 0, "text": text }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let corrections = index.correct(&rtxn, "hello").unwrap();
+        // the same limit as the one-typo derivations of the query words
+        assert_eq!(corrections.len(), 150);
+        assert!(corrections.iter().all(|(word, _)| word != "hello"));
+    }
+
+    #[test]
+    fn readers_see_the_last_committed_state() {
+        let index = TempIndex::new();
+        index.add_documents(documents!([{ "id": 0, "text": "hello" }])).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let mut wtxn = index.write_txn().unwrap();
+        index
+            .add_documents_using_wtxn(&mut wtxn, documents!([{ "id": 1, "text": "hello" }]))
+            .unwrap();
+
+        // a search running on a clone of the index, while the write transaction is in flight
+        let handle = index.inner.clone();
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                let rtxn = handle.read_txn().unwrap();
+                let results = handle.search(&rtxn).query("hello").execute().unwrap();
+                assert_eq!(results.documents_ids, vec![0]);
+            });
+        });
+
+        wtxn.commit().unwrap();
+
+        // a read transaction keeps seeing the state it was opened with
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 1);
+        drop(rtxn);
+
+        let rtxn = index.read_txn().unwrap();
+        assert_eq!(index.number_of_documents(&rtxn).unwrap(), 2);
+        let results = index.search(&rtxn).query("hello").execute().unwrap();
+        assert_eq!(results.documents_ids, vec![0, 1]);
+    }
+
+    #[test]
+    fn open_read_only() {
+        let index = TempIndex::new();
+        index.add_documents(documents!([{ "id": 0, "text": "hello" }])).unwrap();
+
+        // the environment must be closed before being reopened with other flags
+        let TempIndex { inner, _tempdir, .. } = index;
+        let path = inner.path().to_owned();
+        inner.prepare_for_closing().wait();
+
+        let index = Index::open_read_only(EnvOpenOptions::new(), &path).unwrap();
+        let rtxn = index.read_txn().unwrap();
+        let results = index.search(&rtxn).query("hello").execute().unwrap();
+        assert_eq!(results.documents_ids, vec![0]);
+
+        let word_docids =
+            index.database(db_name::WORD_DOCIDS).unwrap().remap_key_type::<heed::types::Str>();
+        assert!(word_docids.get(&rtxn, "hello").unwrap().is_some());
+        assert!(index.database("missing").is_none());
+    }
 }
@@ -31,6 +31,7 @@ pub mod vector;
 pub mod snapshot_tests;
 mod fieldids_weights_map;
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
@@ -362,6 +363,49 @@ pub fn is_faceted_by(field: &str, facet: &str) -> bool {
     field.starts_with(facet) && field[facet.len()..].chars().next().map_or(true, |c| c == '.')
 }
 
+/// The separator between a truncated word and its hash, tokens never contain control characters.
+const TRUNCATED_WORD_SEPARATOR: char = '\u{1f}';
+
+/// The length of the separator followed by the hexadecimal hash of a truncated word.
+const TRUNCATED_WORD_SUFFIX_LENGTH: usize = 1 + 16;
+
+/// Truncates a word to fit in [`MAX_WORD_LENGTH`] bytes.
+///
+/// The longest prefix that fits is followed by a hash of the whole word, this way the long
+/// words sharing the same prefix are not merged into the same postings. The cut always
+/// happens on a char boundary and the hash is stable, the same word is always truncated
+/// the same way both at indexing and search time.
+pub fn truncate_word(word: &str) -> Cow<'_, str> {
+    if word.len() <= MAX_WORD_LENGTH {
+        return Cow::Borrowed(word);
+    }
+
+    let mut end = MAX_WORD_LENGTH - TRUNCATED_WORD_SUFFIX_LENGTH;
+    while !word.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    // FNV-1a, the hashes are stored in the indexes and must never change.
+    let hash = word.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    Cow::Owned(format!("{}{TRUNCATED_WORD_SEPARATOR}{hash:016x}", &word[..end]))
+}
+
+/// Trims a token and prepares it to be indexed, returns `None` if it must be skipped.
+///
+/// The indexes created before the long words were truncated keep dropping them,
+/// see [`Index::truncate_long_words`].
+pub(crate) fn indexable_word(word: &str, truncate_long_words: bool) -> Option<Cow<'_, str>> {
+    let word = word.trim();
+    if word.is_empty() || (!truncate_long_words && word.len() > MAX_WORD_LENGTH) {
+        None
+    } else {
+        Some(truncate_word(word))
+    }
+}
+
 pub fn normalize_facet(original: &str) -> String {
     CompatibilityDecompositionNormalizer.normalize_str(original.trim()).to_lowercase()
 }
@@ -400,6 +444,29 @@ mod tests {
         assert_eq!(string, "name: John Doe. . 43. hello. I. am. fine. . ");
     }
 
+    #[test]
+    fn truncate_long_words() {
+        assert_eq!(truncate_word("hello"), "hello");
+
+        let word = "a".repeat(MAX_WORD_LENGTH + 1);
+        let truncated = truncate_word(&word);
+        let prefix = "a".repeat(MAX_WORD_LENGTH - TRUNCATED_WORD_SUFFIX_LENGTH);
+        assert_eq!(truncated, format!("{prefix}\u{1f}032fd0337586c62a"));
+        assert_eq!(truncated.len(), MAX_WORD_LENGTH);
+
+        // the long words sharing the same prefix must not be merged
+        let other = "a".repeat(MAX_WORD_LENGTH + 2);
+        assert_ne!(truncate_word(&other), truncated);
+        assert!(truncate_word(&other).starts_with(&prefix));
+
+        // `é` is two bytes long and must never be cut in half
+        let prefix = "a".repeat(MAX_WORD_LENGTH - TRUNCATED_WORD_SUFFIX_LENGTH - 1);
+        let word = format!("{prefix}é{}", "a".repeat(10));
+        let truncated = truncate_word(&word);
+        assert_eq!(truncated.strip_prefix(&prefix).unwrap().chars().next(), Some('\u{1f}'));
+        assert!(truncated.len() < MAX_WORD_LENGTH);
+    }
+
     #[test]
     fn test_relative_position_conversion() {
         assert_eq!((0x0000, 0x0000), relative_from_absolute_position(0x00000000));
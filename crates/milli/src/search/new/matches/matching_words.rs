@@ -7,7 +7,7 @@ use charabia::Token;
 use super::super::interner::Interned;
 use super::super::query_term::LocatedQueryTerm;
 use super::super::{DedupInterner, Phrase};
-use crate::SearchContext;
+use crate::{truncate_word, SearchContext};
 
 pub struct LocatedMatchingPhrase {
     pub value: Interned<Phrase>,
@@ -89,8 +89,8 @@ impl MatchingWords {
                     let (char_count, byte_len) = token.original_lengths(prefix_length);
                     let ids = &located_words.positions;
                     return Some(MatchType::Full { ids, char_count, byte_len });
-                // else we exact match the token.
-                } else if token.lemma() == word {
+                // else we exact match the token, truncated like the indexed words.
+                } else if truncate_word(token.lemma()) == word {
                     let ids = &located_words.positions;
                     return Some(MatchType::Full {
                         char_count: token.char_end - token.char_start,
@@ -172,7 +172,7 @@ impl<'a> PartialMatch<'a> {
         let Self { mut matching_words, ids, .. } = self;
 
         let is_matching = match matching_words.first()? {
-            Some(word) => &token.lemma() == word,
+            Some(word) => truncate_word(token.lemma()) == *word,
             // a None value in the phrase corresponds to a stop word,
             // the walue is considered a match if the current token is categorized as a stop word.
             None => token.is_stopword(),
@@ -630,6 +630,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn highlight_long_words() {
+        let temp_index = TempIndex::new();
+
+        let long_word = "lol".repeat(1000);
+        let text = format!("{long_word} world");
+        temp_index
+            .add_documents(documents!([
+                { "id": 1, "text": text }
+            ]))
+            .unwrap();
+
+        let rtxn = temp_index.read_txn().unwrap();
+        let format_options = FormatOptions { highlight: true, crop: None };
+
+        // the query word is truncated but the whole word must be highlighted.
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, &text);
+        let mut matcher = builder.build(&text, None);
+        assert_eq!(matcher.format(format_options), format!("<em>{long_word}</em> <em>world</em>"));
+
+        // same thing in a phrase.
+        let builder = MatcherBuilder::new_test(&rtxn, &temp_index, &format!("\"{text}\""));
+        let mut matcher = builder.build(&text, None);
+        assert_eq!(matcher.format(format_options), format!("<em>{long_word} world</em>"));
+    }
+
     #[test]
     fn format_crop() {
         let temp_index = temp_index_with_documents();
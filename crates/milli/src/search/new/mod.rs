@@ -33,6 +33,7 @@ use interner::{DedupInterner, Interner};
 pub use logger::visual::VisualSearchLogger;
 pub use logger::{DefaultSearchLogger, SearchLogger};
 use query_graph::{QueryGraph, QueryNode};
+pub(crate) use query_term::{find_typo_corrections, number_of_typos_allowed_in_index};
 use query_term::{
     located_query_terms_from_tokens, ExtractedTokens, LocatedQueryTerm, Phrase, QueryTerm,
 };
@@ -12,7 +12,7 @@ use crate::search::new::interner::{DedupInterner, Interned};
 use crate::search::new::query_term::{Lazy, TwoTypoTerm};
 use crate::search::new::{limits, SearchContext};
 use crate::search::{build_dfa, get_first};
-use crate::{Result, MAX_WORD_LENGTH};
+use crate::{truncate_word, Result};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NumberOfTypos {
@@ -70,57 +70,38 @@ fn find_zero_typo_prefix_derivations(
     Ok(())
 }
 
-fn find_zero_one_typo_derivations(
-    ctx: &mut SearchContext<'_>,
-    word_interned: Interned<String>,
+/// Visits the words of the fst that are zero or one typo away from the given word.
+fn visit_zero_one_typo_words(
+    fst: &fst::Set<Cow<'_, [u8]>>,
+    word: &str,
     is_prefix: bool,
-    mut visit: impl FnMut(Interned<String>, ZeroOrOneTypo) -> Result<ControlFlow<()>>,
+    mut visit: impl FnMut(&str, ZeroOrOneTypo) -> Result<ControlFlow<()>>,
 ) -> Result<()> {
-    let fst = ctx.get_words_fst()?;
-    let word = ctx.word_interner.get(word_interned).to_owned();
-    let word = word.as_str();
-
     let dfa = build_dfa(word, 1, is_prefix);
     let starts = StartsWith(Str::new(get_first(word)));
     let mut stream = fst.search_with_state(Intersection(starts, &dfa)).into_stream();
 
     while let Some((derived_word, state)) = stream.next() {
         let derived_word = std::str::from_utf8(derived_word)?;
-        let derived_word = ctx.word_interner.insert(derived_word.to_owned());
-        let d = dfa.distance(state.1);
-        match d.to_u8() {
-            0 => {
-                if derived_word != word_interned {
-                    let cf = visit(derived_word, ZeroOrOneTypo::Zero)?;
-                    if cf.is_break() {
-                        break;
-                    }
-                }
-            }
-            1 => {
-                let cf = visit(derived_word, ZeroOrOneTypo::One)?;
-                if cf.is_break() {
-                    break;
-                }
-            }
-            _ => {
-                unreachable!("One typo dfa produced multiple typos")
-            }
+        let nbr_typos = match dfa.distance(state.1).to_u8() {
+            0 => ZeroOrOneTypo::Zero,
+            1 => ZeroOrOneTypo::One,
+            _ => unreachable!("One typo dfa produced multiple typos"),
+        };
+        if visit(derived_word, nbr_typos)?.is_break() {
+            break;
         }
     }
     Ok(())
 }
 
-fn find_zero_one_two_typo_derivations(
-    word_interned: Interned<String>,
+/// Visits the words of the fst that are zero, one or two typos away from the given word.
+fn visit_zero_one_two_typo_words(
+    fst: &fst::Set<Cow<'_, [u8]>>,
+    word: &str,
     is_prefix: bool,
-    fst: fst::Set<Cow<'_, [u8]>>,
-    word_interner: &mut DedupInterner<String>,
-    mut visit: impl FnMut(Interned<String>, NumberOfTypos) -> Result<ControlFlow<()>>,
+    mut visit: impl FnMut(&str, NumberOfTypos) -> Result<ControlFlow<()>>,
 ) -> Result<()> {
-    let word = word_interner.get(word_interned).to_owned();
-    let word = word.as_str();
-
     let starts = StartsWith(Str::new(get_first(word)));
     let first = Intersection(build_dfa(word, 1, is_prefix), Complement(&starts));
     let second_dfa = build_dfa(word, 2, is_prefix);
@@ -131,44 +112,115 @@ fn find_zero_one_two_typo_derivations(
 
     while let Some((derived_word, state)) = stream.next() {
         let derived_word = std::str::from_utf8(derived_word)?;
-        let derived_word_interned = word_interner.insert(derived_word.to_owned());
         // in the case the typo is on the first letter, we know the number of typo
         // is two
-        if get_first(derived_word) != get_first(word) {
-            let cf = visit(derived_word_interned, NumberOfTypos::Two)?;
-            if cf.is_break() {
-                break;
-            }
+        let nbr_typos = if get_first(derived_word) != get_first(word) {
+            NumberOfTypos::Two
         } else {
             // Else, we know that it is the second dfa that matched and compute the
             // correct distance
-            let d = second_dfa.distance((state.1).0);
-            match d.to_u8() {
-                0 => {
-                    if derived_word_interned != word_interned {
-                        let cf = visit(derived_word_interned, NumberOfTypos::Zero)?;
-                        if cf.is_break() {
-                            break;
-                        }
-                    }
+            match second_dfa.distance((state.1).0).to_u8() {
+                0 => NumberOfTypos::Zero,
+                1 => NumberOfTypos::One,
+                2 => NumberOfTypos::Two,
+                _ => unreachable!("2 typos DFA produced a distance greater than 2"),
+            }
+        };
+        if visit(derived_word, nbr_typos)?.is_break() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn find_zero_one_typo_derivations(
+    ctx: &mut SearchContext<'_>,
+    word_interned: Interned<String>,
+    is_prefix: bool,
+    mut visit: impl FnMut(Interned<String>, ZeroOrOneTypo) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    let fst = ctx.get_words_fst()?;
+    let word = ctx.word_interner.get(word_interned).to_owned();
+
+    visit_zero_one_typo_words(&fst, &word, is_prefix, |derived_word, nbr_typos| {
+        let derived_word = ctx.word_interner.insert(derived_word.to_owned());
+        match nbr_typos {
+            ZeroOrOneTypo::Zero if derived_word == word_interned => Ok(ControlFlow::Continue(())),
+            nbr_typos => visit(derived_word, nbr_typos),
+        }
+    })
+}
+
+fn find_zero_one_two_typo_derivations(
+    word_interned: Interned<String>,
+    is_prefix: bool,
+    fst: fst::Set<Cow<'_, [u8]>>,
+    word_interner: &mut DedupInterner<String>,
+    mut visit: impl FnMut(Interned<String>, NumberOfTypos) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    let word = word_interner.get(word_interned).to_owned();
+
+    visit_zero_one_two_typo_words(&fst, &word, is_prefix, |derived_word, nbr_typos| {
+        let derived_word = word_interner.insert(derived_word.to_owned());
+        match nbr_typos {
+            NumberOfTypos::Zero if derived_word == word_interned => Ok(ControlFlow::Continue(())),
+            nbr_typos => visit(derived_word, nbr_typos),
+        }
+    })
+}
+
+/// Returns the words of the fst that are one or two typos away from the given word,
+/// along with their number of typos.
+///
+/// Like for the query terms, a typo on the first letter counts as two typos and the
+/// number of words is capped by [`limits::MAX_ONE_TYPO_COUNT`] and [`limits::MAX_TWO_TYPOS_COUNT`].
+pub fn find_typo_corrections(
+    fst: &fst::Set<Cow<'_, [u8]>>,
+    word: &str,
+    max_typos: u8,
+) -> Result<Vec<(String, u8)>> {
+    let mut one_typo_count = 0;
+    let mut two_typos_count = 0;
+    let mut corrections = Vec::new();
+
+    match max_typos {
+        0 => (),
+        1 => visit_zero_one_typo_words(fst, word, false, |derived_word, nbr_typos| {
+            if let ZeroOrOneTypo::One = nbr_typos {
+                if one_typo_count >= limits::MAX_ONE_TYPO_COUNT {
+                    return Ok(ControlFlow::Break(()));
                 }
-                1 => {
-                    let cf = visit(derived_word_interned, NumberOfTypos::One)?;
-                    if cf.is_break() {
-                        break;
+                one_typo_count += 1;
+                corrections.push((derived_word.to_owned(), 1));
+            }
+            Ok(ControlFlow::Continue(()))
+        })?,
+        _ => visit_zero_one_two_typo_words(fst, word, false, |derived_word, nbr_typos| {
+            if one_typo_count >= limits::MAX_ONE_TYPO_COUNT
+                && two_typos_count >= limits::MAX_TWO_TYPOS_COUNT
+            {
+                return Ok(ControlFlow::Break(()));
+            }
+            match nbr_typos {
+                NumberOfTypos::Zero => {}
+                NumberOfTypos::One => {
+                    if one_typo_count < limits::MAX_ONE_TYPO_COUNT {
+                        one_typo_count += 1;
+                        corrections.push((derived_word.to_owned(), 1));
                     }
                 }
-                2 => {
-                    let cf = visit(derived_word_interned, NumberOfTypos::Two)?;
-                    if cf.is_break() {
-                        break;
+                NumberOfTypos::Two => {
+                    if two_typos_count < limits::MAX_TWO_TYPOS_COUNT {
+                        two_typos_count += 1;
+                        corrections.push((derived_word.to_owned(), 2));
                     }
                 }
-                _ => unreachable!("2 typos DFA produced a distance greater than 2"),
             }
-        }
+            Ok(ControlFlow::Continue(()))
+        })?,
     }
-    Ok(())
+
+    Ok(corrections)
 }
 
 pub fn partially_initialized_term_from_word(
@@ -178,22 +230,12 @@ pub fn partially_initialized_term_from_word(
     is_prefix: bool,
     is_ngram: bool,
 ) -> Result<QueryTerm> {
+    // Words longer than `MAX_WORD_LENGTH` are truncated at indexing time,
+    // we truncate them the same way to be able to find them.
+    let truncated = truncate_word(word);
+    let word = truncated.as_ref();
     let word_interned = ctx.word_interner.insert(word.to_owned());
 
-    if word.len() > MAX_WORD_LENGTH {
-        return Ok({
-            QueryTerm {
-                original: ctx.word_interner.insert(word.to_owned()),
-                ngram_words: None,
-                is_prefix: false,
-                max_levenshtein_distance: 0,
-                zero_typo: <_>::default(),
-                one_typo: Lazy::Init(<_>::default()),
-                two_typo: Lazy::Init(<_>::default()),
-            }
-        });
-    }
-
     let fst = ctx.index.words_fst(ctx.txn)?;
 
     let use_prefix_db = is_prefix
@@ -7,10 +7,12 @@ use std::collections::BTreeSet;
 use std::iter::FromIterator;
 use std::ops::RangeInclusive;
 
+pub use compute_derivations::find_typo_corrections;
 use either::Either;
 pub use ntypo_subset::NTypoTermSubset;
 pub use parse_query::{
-    located_query_terms_from_tokens, make_ngram, number_of_typos_allowed, ExtractedTokens,
+    located_query_terms_from_tokens, make_ngram, number_of_typos_allowed,
+    number_of_typos_allowed_in_index, ExtractedTokens,
 };
 pub use phrase::Phrase;
 
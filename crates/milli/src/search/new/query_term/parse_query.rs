@@ -2,12 +2,13 @@ use std::collections::BTreeSet;
 
 use charabia::normalizer::NormalizedTokenIter;
 use charabia::{SeparatorKind, TokenKind};
+use heed::RoTxn;
 
 use super::compute_derivations::partially_initialized_term_from_word;
 use super::{LocatedQueryTerm, ZeroTypoTerm};
 use crate::search::new::query_term::{Lazy, Phrase, QueryTerm};
 use crate::search::new::Word;
-use crate::{Result, SearchContext, MAX_WORD_LENGTH};
+use crate::{truncate_word, Index, Result, SearchContext, MAX_WORD_LENGTH};
 
 #[derive(Clone)]
 /// Extraction of the content of a query.
@@ -65,7 +66,7 @@ pub fn located_query_terms_from_tokens(
                 if let Some(phrase) = &mut phrase {
                     phrase.push_word(ctx, &token, position)
                 } else if negative_next_token {
-                    let word = token.lemma().to_string();
+                    let word = truncate_word(token.lemma()).to_string();
                     let word = Word::Original(ctx.word_interner.insert(word));
                     negative_words.push(word);
                     negative_next_token = false;
@@ -193,11 +194,19 @@ pub fn located_query_terms_from_tokens(
 pub fn number_of_typos_allowed<'ctx>(
     ctx: &SearchContext<'ctx>,
 ) -> Result<impl Fn(&str) -> u8 + 'ctx> {
-    let authorize_typos = ctx.index.authorize_typos(ctx.txn)?;
-    let min_len_one_typo = ctx.index.min_word_len_one_typo(ctx.txn)?;
-    let min_len_two_typos = ctx.index.min_word_len_two_typos(ctx.txn)?;
+    number_of_typos_allowed_in_index(ctx.index, ctx.txn)
+}
+
+/// Same as [`number_of_typos_allowed`] but without a search context.
+pub fn number_of_typos_allowed_in_index<'t>(
+    index: &Index,
+    txn: &'t RoTxn<'t>,
+) -> Result<impl Fn(&str) -> u8 + 't> {
+    let authorize_typos = index.authorize_typos(txn)?;
+    let min_len_one_typo = index.min_word_len_one_typo(txn)?;
+    let min_len_two_typos = index.min_word_len_two_typos(txn)?;
 
-    let exact_words = ctx.index.exact_words(ctx.txn)?;
+    let exact_words = index.exact_words(txn)?;
 
     Ok(Box::new(move |word: &str| {
         if !authorize_typos
@@ -311,7 +320,7 @@ impl PhraseBuilder {
             self.words.push(None);
         } else {
             // token has kind Word
-            let word = ctx.word_interner.insert(token.lemma().to_string());
+            let word = ctx.word_interner.insert(truncate_word(token.lemma()).to_string());
             self.words.push(Some(word));
         }
     }
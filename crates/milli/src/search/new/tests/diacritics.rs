@@ -0,0 +1,112 @@
+/*!
+This module tests the following properties about diacritics and unicode normalization:
+
+1. A query without diacritics matches words indexed with diacritics
+2. A query with diacritics matches words indexed without diacritics
+3. Normalization is applied on top of lowercasing, in both directions
+4. These matches do not rely on typo tolerance
+*/
+
+use crate::index::tests::TempIndex;
+use crate::search::new::tests::collect_field_values;
+use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words]);
+            // make sure that matches only come from the normalization
+            s.set_autorize_typos(false);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            {
+                "id": 0,
+                "text": "un café au lait"
+            },
+            {
+                "id": 1,
+                "text": "un cafe noir"
+            },
+            {
+                "id": 2,
+                "text": "joyeux Noël"
+            },
+            {
+                "id": 3,
+                "text": "joyeux noel"
+            },
+            {
+                "id": 4,
+                "text": "CAFÉ CRÈME"
+            },
+            {
+                "id": 5,
+                "text": "the coffee shop"
+            }
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_query_without_diacritics() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("cafe ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 4]");
+    let texts = collect_field_values(&index, &txn, "text", &documents_ids);
+    insta::assert_debug_snapshot!(texts, @r###"
+    [
+        "\"un café au lait\"",
+        "\"un cafe noir\"",
+        "\"CAFÉ CRÈME\"",
+    ]
+    "###);
+}
+
+#[test]
+fn test_query_with_diacritics() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("café ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 4]");
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("noël ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 3]");
+}
+
+#[test]
+fn test_diacritics_and_case() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("NOEL ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 3]");
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("cafe creme");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[4]");
+}
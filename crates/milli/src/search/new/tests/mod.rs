@@ -1,6 +1,7 @@
 pub mod attribute_fid;
 pub mod attribute_position;
 pub mod cutoff;
+pub mod diacritics;
 pub mod distinct;
 pub mod exactness;
 pub mod geo_sort;
@@ -13,6 +14,7 @@ pub mod proximity;
 pub mod proximity_typo;
 pub mod sort;
 pub mod stop_words;
+pub mod synonyms;
 pub mod typo;
 pub mod typo_proximity;
 pub mod words_tms;
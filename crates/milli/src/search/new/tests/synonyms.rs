@@ -0,0 +1,161 @@
+/*!
+This module tests the following properties about synonyms:
+
+1. A query word is expanded into its single-word synonyms
+2. A query word is expanded into its multi-word synonyms, which are matched as phrases
+3. A multi-word synonym does not match documents where its words are not consecutive
+4. Consecutive query words forming a 2gram are expanded into the synonyms of that 2gram
+5. A multi-word query can be expanded into a multi-word synonym, which is matched as a phrase
+6. Synonyms are one-way: a synonym is not expanded into the words it is a synonym of
+*/
+
+use std::collections::BTreeMap;
+
+use crate::index::tests::TempIndex;
+use crate::search::new::tests::collect_field_values;
+use crate::{Criterion, Search, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["text".to_owned()]);
+            s.set_criteria(vec![Criterion::Words]);
+            s.set_autorize_typos(false);
+
+            let mut synonyms = BTreeMap::new();
+            synonyms.insert("ny".to_owned(), vec!["new york".to_owned()]);
+            synonyms.insert("new york".to_owned(), vec!["nyc".to_owned(), "big apple".to_owned()]);
+            synonyms.insert(
+                "subway".to_owned(),
+                vec!["metro".to_owned(), "underground railway".to_owned()],
+            );
+            s.set_synonyms(synonyms);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            {
+                "id": 0,
+                "text": "the new york subway"
+            },
+            {
+                "id": 1,
+                "text": "york is a new city"
+            },
+            {
+                "id": 2,
+                "text": "the nyc metro"
+            },
+            {
+                "id": 3,
+                "text": "the underground railway of london"
+            },
+            {
+                "id": 4,
+                "text": "the railway goes underground"
+            },
+            {
+                "id": 5,
+                "text": "the big apple at night"
+            },
+            {
+                "id": 6,
+                "text": "an apple is big at night"
+            }
+        ]))
+        .unwrap();
+    index
+}
+
+#[test]
+fn test_single_and_multi_word_synonyms() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("subway ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    // `metro` is a one-word synonym and `underground railway` is matched as a phrase,
+    // which is why document 4 is not returned
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 2, 3]");
+    let texts = collect_field_values(&index, &txn, "text", &documents_ids);
+    insta::assert_debug_snapshot!(texts, @r###"
+    [
+        "\"the new york subway\"",
+        "\"the nyc metro\"",
+        "\"the underground railway of london\"",
+    ]
+    "###);
+}
+
+#[test]
+fn test_multi_word_synonym_is_a_phrase() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("ny ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    // document 1 contains both `new` and `york`, but not consecutively
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+}
+
+#[test]
+fn test_ngram_synonyms() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("new york ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    // documents 2 and 5 are only matched through the synonyms of the `new york` 2gram
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1, 2, 5]");
+    let texts = collect_field_values(&index, &txn, "text", &documents_ids);
+    insta::assert_debug_snapshot!(texts, @r###"
+    [
+        "\"the new york subway\"",
+        "\"york is a new city\"",
+        "\"the nyc metro\"",
+        "\"the big apple at night\"",
+    ]
+    "###);
+}
+
+#[test]
+fn test_phrase_to_phrase_synonyms() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("new york at night");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    // `big apple` is matched as a phrase, which is why document 6 is not returned
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[5]");
+}
+
+#[test]
+fn test_synonyms_are_one_way() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("nyc ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    // `nyc` is a synonym of `new york`, but `new york` is not a synonym of `nyc`
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2]");
+
+    let mut s = Search::new(&txn, &index);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.query("metro ");
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2]");
+}
@@ -1,7 +1,6 @@
 use std::borrow::Cow;
 use std::collections::BTreeSet;
 use std::io;
-use std::result::Result as StdResult;
 
 use either::Either;
 use grenad::MergeFunction;
@@ -10,7 +9,7 @@ use roaring::RoaringBitmap;
 use crate::heed_codec::CboRoaringBitmapCodec;
 use crate::update::del_add::{DelAdd, KvReaderDelAdd, KvWriterDelAdd};
 use crate::update::index_documents::transform::Operation;
-use crate::Result;
+use crate::{InternalError, Result};
 
 pub type EitherObkvMerge =
     Either<ObkvsKeepLastAdditionMergeDeletions, ObkvsMergeAdditionsAndDeletions>;
@@ -26,17 +25,19 @@ pub struct MergeRoaringBitmaps;
 impl MergeFunction for MergeRoaringBitmaps {
     type Error = crate::Error;
 
-    fn merge<'a>(&self, _key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
+    fn merge<'a>(&self, key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
         if values.len() == 1 {
             Ok(values[0].clone())
         } else {
-            let merged = values
-                .iter()
-                .map(AsRef::as_ref)
-                .map(RoaringBitmap::deserialize_from)
-                .map(StdResult::unwrap)
-                .reduce(|a, b| a | b)
-                .unwrap();
+            let mut merged = RoaringBitmap::new();
+            for value in values {
+                merged |= RoaringBitmap::deserialize_from(value.as_ref()).map_err(|_| {
+                    InternalError::IndexingMergingValue {
+                        process: "roaring bitmaps merging",
+                        key: key.to_vec(),
+                    }
+                })?;
+            }
             let mut buffer = Vec::new();
             serialize_roaring_bitmap(&merged, &mut buffer)?;
             Ok(Cow::Owned(buffer))
@@ -132,17 +133,21 @@ pub fn merge_two_del_add_obkvs(
 
 /// Merge all the obkvs from the newest to the oldest.
 fn inner_merge_del_add_obkvs<'a>(
+    key: &[u8],
     obkvs: &[Cow<'a, [u8]>],
     merge_additions: bool,
 ) -> Result<Cow<'a, [u8]>> {
+    let invalid_value =
+        || InternalError::IndexingMergingValue { process: "documents merging", key: key.to_vec() };
+
     // pop the newest operation from the list.
-    let (newest, obkvs) = obkvs.split_last().unwrap();
+    let (newest, obkvs) = obkvs.split_last().ok_or_else(invalid_value)?;
     // keep the operation type for the returned value.
-    let newest_operation_type = newest[0];
+    let (&newest_operation_type, newest) = newest.split_first().ok_or_else(invalid_value)?;
 
     // treat the newest obkv as the starting point of the merge.
     let mut acc_operation_type = newest_operation_type;
-    let mut acc = newest[1..].to_vec();
+    let mut acc = newest.to_vec();
     let mut buffer = Vec::new();
     // reverse iter from the most recent to the oldest.
     for current in obkvs.iter().rev() {
@@ -152,13 +157,14 @@ fn inner_merge_del_add_obkvs<'a>(
             break;
         }
 
+        let (&current_operation_type, current) = current.split_first().ok_or_else(invalid_value)?;
         let newest = obkv::KvReader::from_slice(&acc);
-        let oldest = obkv::KvReader::from_slice(&current[1..]);
+        let oldest = obkv::KvReader::from_slice(current);
         merge_two_del_add_obkvs(oldest, newest, merge_additions, &mut buffer);
 
         // we want the result of the merge into our accumulator.
         std::mem::swap(&mut acc, &mut buffer);
-        acc_operation_type = current[0];
+        acc_operation_type = current_operation_type;
     }
 
     acc.insert(0, newest_operation_type);
@@ -172,8 +178,8 @@ pub struct ObkvsMergeAdditionsAndDeletions;
 impl MergeFunction for ObkvsMergeAdditionsAndDeletions {
     type Error = crate::Error;
 
-    fn merge<'a>(&self, _key: &[u8], obkvs: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
-        inner_merge_del_add_obkvs(obkvs, true)
+    fn merge<'a>(&self, key: &[u8], obkvs: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
+        inner_merge_del_add_obkvs(key, obkvs, true)
     }
 }
 
@@ -184,8 +190,8 @@ pub struct ObkvsKeepLastAdditionMergeDeletions;
 impl MergeFunction for ObkvsKeepLastAdditionMergeDeletions {
     type Error = crate::Error;
 
-    fn merge<'a>(&self, _key: &[u8], obkvs: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
-        inner_merge_del_add_obkvs(obkvs, false)
+    fn merge<'a>(&self, key: &[u8], obkvs: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
+        inner_merge_del_add_obkvs(key, obkvs, false)
     }
 }
 
@@ -195,12 +201,17 @@ pub struct MergeCboRoaringBitmaps;
 impl MergeFunction for MergeCboRoaringBitmaps {
     type Error = crate::Error;
 
-    fn merge<'a>(&self, _key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
+    fn merge<'a>(&self, key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
         if values.len() == 1 {
             Ok(values[0].clone())
         } else {
             let mut vec = Vec::new();
-            CboRoaringBitmapCodec::merge_into(values, &mut vec)?;
+            CboRoaringBitmapCodec::merge_into(values, &mut vec).map_err(|_| {
+                InternalError::IndexingMergingValue {
+                    process: "cbo roaring bitmaps merging",
+                    key: key.to_vec(),
+                }
+            })?;
             Ok(Cow::from(vec))
         }
     }
@@ -213,10 +224,15 @@ pub struct MergeDeladdCboRoaringBitmaps;
 impl MergeFunction for MergeDeladdCboRoaringBitmaps {
     type Error = crate::Error;
 
-    fn merge<'a>(&self, _key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
+    fn merge<'a>(&self, key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
         if values.len() == 1 {
             Ok(values[0].clone())
         } else {
+            let invalid_value = |_| InternalError::IndexingMergingValue {
+                process: "deladd cbo roaring bitmaps merging",
+                key: key.to_vec(),
+            };
+
             // Retrieve the bitmaps from both sides
             let mut del_bitmaps_bytes = Vec::new();
             let mut add_bitmaps_bytes = Vec::new();
@@ -232,10 +248,12 @@ impl MergeFunction for MergeDeladdCboRoaringBitmaps {
 
             let mut output_deladd_obkv = KvWriterDelAdd::memory();
             let mut buffer = Vec::new();
-            CboRoaringBitmapCodec::merge_into(del_bitmaps_bytes, &mut buffer)?;
+            CboRoaringBitmapCodec::merge_into(del_bitmaps_bytes, &mut buffer)
+                .map_err(invalid_value)?;
             output_deladd_obkv.insert(DelAdd::Deletion, &buffer)?;
             buffer.clear();
-            CboRoaringBitmapCodec::merge_into(add_bitmaps_bytes, &mut buffer)?;
+            CboRoaringBitmapCodec::merge_into(add_bitmaps_bytes, &mut buffer)
+                .map_err(invalid_value)?;
             output_deladd_obkv.insert(DelAdd::Addition, &buffer)?;
             output_deladd_obkv.into_inner().map(Cow::from).map_err(Into::into)
         }
@@ -265,33 +283,35 @@ pub struct MergeDeladdBtreesetString;
 impl MergeFunction for MergeDeladdBtreesetString {
     type Error = crate::Error;
 
-    fn merge<'a>(&self, _key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
+    fn merge<'a>(&self, key: &[u8], values: &[Cow<'a, [u8]>]) -> Result<Cow<'a, [u8]>> {
         if values.len() == 1 {
             Ok(values[0].clone())
         } else {
             // Retrieve the bitmaps from both sides
             let mut del_set = BTreeSet::new();
             let mut add_set = BTreeSet::new();
+            let deserialize = |bytes: &[u8]| {
+                serde_json::from_slice::<BTreeSet<String>>(bytes).map_err(|_| {
+                    InternalError::IndexingMergingValue {
+                        process: "string sets merging",
+                        key: key.to_vec(),
+                    }
+                })
+            };
             for value in values {
                 let obkv = KvReaderDelAdd::from_slice(value);
                 if let Some(bytes) = obkv.get(DelAdd::Deletion) {
-                    let set = serde_json::from_slice::<BTreeSet<String>>(bytes).unwrap();
-                    for value in set {
-                        del_set.insert(value);
-                    }
+                    del_set.extend(deserialize(bytes)?);
                 }
                 if let Some(bytes) = obkv.get(DelAdd::Addition) {
-                    let set = serde_json::from_slice::<BTreeSet<String>>(bytes).unwrap();
-                    for value in set {
-                        add_set.insert(value);
-                    }
+                    add_set.extend(deserialize(bytes)?);
                 }
             }
 
             let mut output_deladd_obkv = KvWriterDelAdd::memory();
-            let del = serde_json::to_vec(&del_set).unwrap();
+            let del = serde_json::to_vec(&del_set).map_err(InternalError::SerdeJson)?;
             output_deladd_obkv.insert(DelAdd::Deletion, &del)?;
-            let add = serde_json::to_vec(&add_set).unwrap();
+            let add = serde_json::to_vec(&add_set).map_err(InternalError::SerdeJson)?;
             output_deladd_obkv.insert(DelAdd::Addition, &add)?;
             output_deladd_obkv.into_inner().map(Cow::from).map_err(Into::into)
         }
@@ -312,3 +332,50 @@ impl MergeFunction for MergeIgnoreValues {
         Ok(Cow::Owned(Vec::new()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    fn assert_invalid_value(result: Result<Cow<'_, [u8]>>, expected_process: &str) {
+        match result {
+            Err(Error::InternalError(InternalError::IndexingMergingValue { process, key })) => {
+                assert_eq!(process, expected_process);
+                assert_eq!(key, b"hello");
+            }
+            otherwise => panic!("expected an invalid merging value error, got {otherwise:?}"),
+        }
+    }
+
+    #[test]
+    fn corrupted_roaring_bitmap() {
+        let mut valid = Vec::new();
+        serialize_roaring_bitmap(&RoaringBitmap::from_iter([1, 2, 3]), &mut valid).unwrap();
+        let values = [Cow::from(valid), Cow::from(&b"corrupted"[..])];
+
+        let result = MergeRoaringBitmaps.merge(b"hello", &values);
+        assert_invalid_value(result, "roaring bitmaps merging");
+    }
+
+    #[test]
+    fn corrupted_string_set() {
+        let mut valid = KvWriterDelAdd::memory();
+        valid.insert(DelAdd::Addition, br#"["hello"]"#).unwrap();
+        let mut corrupted = KvWriterDelAdd::memory();
+        corrupted.insert(DelAdd::Addition, br#"["hello""#).unwrap();
+        let values =
+            [Cow::from(valid.into_inner().unwrap()), Cow::from(corrupted.into_inner().unwrap())];
+
+        let result = MergeDeladdBtreesetString.merge(b"hello", &values);
+        assert_invalid_value(result, "string sets merging");
+    }
+
+    #[test]
+    fn empty_document_operation() {
+        let values = [Cow::from(vec![Operation::Addition as u8]), Cow::from(Vec::new())];
+
+        let result = ObkvsMergeAdditionsAndDeletions.merge(b"hello", &values);
+        assert_invalid_value(result, "documents merging");
+    }
+}
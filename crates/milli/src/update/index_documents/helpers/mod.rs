@@ -16,6 +16,12 @@ pub fn valid_lmdb_key(key: impl AsRef<[u8]>) -> bool {
     key.as_ref().len() <= MAX_WORD_LENGTH * 2 && !key.as_ref().is_empty()
 }
 
+/// A word pair proximity key holds two words, the proximity and a separator,
+/// it can be a little bit longer than the other keys but still fits in a LMDB key.
+pub fn valid_lmdb_word_pair_proximity_key(key: impl AsRef<[u8]>) -> bool {
+    key.as_ref().len() <= MAX_WORD_LENGTH * 2 + 2 && !key.as_ref().is_empty()
+}
+
 /// Divides one slice into two at an index, returns `None` if mid is out of bounds.
 pub fn try_split_at<T>(slice: &[T], mid: usize) -> Option<(&[T], &[T])> {
     if mid <= slice.len() {
@@ -749,6 +749,7 @@ mod tests {
     use crate::documents::mmap_from_objects;
     use crate::index::tests::TempIndex;
     use crate::index::IndexEmbeddingConfig;
+    use crate::proximity::ProximityPrecision;
     use crate::search::TermsMatchingStrategy;
     use crate::update::new::indexer;
     use crate::update::Setting;
@@ -2037,7 +2038,7 @@ mod tests {
     }
 
     #[test]
-    fn long_words_must_be_skipped() {
+    fn long_words_must_be_truncated() {
         let index = TempIndex::new();
 
         // this is obviousy too long
@@ -2052,6 +2053,112 @@ mod tests {
         let rtxn = index.read_txn().unwrap();
         let words_fst = index.words_fst(&rtxn).unwrap();
         assert!(!words_fst.contains(&long_word));
+        assert!(words_fst.contains(crate::truncate_word(&long_word).as_bytes()));
+
+        // the word is truncated the same way at search time
+        let result = index.search(&rtxn).query(&long_word).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+
+        // in phrases too
+        let result = index.search(&rtxn).query(format!("\"{long_word}\"")).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+
+        // and when it is negated
+        let result = index.search(&rtxn).query(format!("-{long_word}")).execute().unwrap();
+        assert!(result.documents_ids.is_empty());
+    }
+
+    #[test]
+    fn long_words_sharing_a_prefix_must_not_be_merged() {
+        let index = TempIndex::new();
+
+        let prefix = "lol".repeat(1000);
+        let documents = documents! {[
+            { "id": "1", "title": format!("{prefix}a") },
+            { "id": "2", "title": format!("{prefix}b") },
+        ]};
+        index.add_documents(documents).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let result = index.search(&rtxn).query(format!("{prefix}a")).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![0]);
+        let result = index.search(&rtxn).query(format!("{prefix}b")).execute().unwrap();
+        assert_eq!(result.documents_ids, vec![1]);
+    }
+
+    #[test]
+    fn long_words_are_truncated_once_the_legacy_indexes_are_reindexed() {
+        let index = TempIndex::new();
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.truncate_long_words(&rtxn).unwrap());
+        drop(rtxn);
+
+        // the indexes created before the long words were truncated dropped them
+        let mut wtxn = index.write_txn().unwrap();
+        index.put_truncate_long_words(&mut wtxn, false).unwrap();
+        wtxn.commit().unwrap();
+
+        let long_word = "lol".repeat(1000);
+        let doc1 = documents! {[{
+            "id": "1",
+            "title": format!("hello {long_word}"),
+        }]};
+        index.add_documents(doc1).unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let title = index.fields_ids_map(&rtxn).unwrap().id("title").unwrap();
+        let words_fst = index.words_fst(&rtxn).unwrap();
+        assert!(!words_fst.contains(crate::truncate_word(&long_word).as_bytes()));
+        let docids = index.field_id_word_count_docids.get(&rtxn, &(title, 1)).unwrap();
+        assert_eq!(docids, Some(RoaringBitmap::from_iter([0])));
+        drop(rtxn);
+
+        // the delete side of the reindexing must drop the long words like the indexing did
+        index
+            .update_settings(|settings| {
+                settings.set_truncate_long_words(true);
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        assert!(index.truncate_long_words(&rtxn).unwrap());
+        let words_fst = index.words_fst(&rtxn).unwrap();
+        assert!(words_fst.contains(crate::truncate_word(&long_word).as_bytes()));
+        let docids = index.field_id_word_count_docids.get(&rtxn, &(title, 1)).unwrap();
+        assert_eq!(docids, None);
+        let docids = index.field_id_word_count_docids.get(&rtxn, &(title, 2)).unwrap();
+        assert_eq!(docids, Some(RoaringBitmap::from_iter([0])));
+    }
+
+    #[test]
+    fn long_word_pairs_must_not_be_skipped() {
+        let index = TempIndex::new();
+        index
+            .update_settings(|settings| {
+                settings.set_proximity_precision(ProximityPrecision::ByAttribute);
+            })
+            .unwrap();
+
+        let first = "lol".repeat(1000);
+        let second = "mdr".repeat(1000);
+        let doc1 = documents! {[{
+            "id": "1",
+            "title": format!("{first} {second}"),
+        }]};
+        index.add_documents(doc1).unwrap();
+
+        // computing the word pair proximities goes through the settings reindexing
+        index
+            .update_settings(|settings| {
+                settings.set_proximity_precision(ProximityPrecision::ByWord);
+            })
+            .unwrap();
+
+        let rtxn = index.read_txn().unwrap();
+        let first = crate::truncate_word(&first);
+        let second = crate::truncate_word(&second);
+        let docids = index.word_pair_proximity_docids.get(&rtxn, &(1, &*first, &*second)).unwrap();
+        assert_eq!(docids, Some(RoaringBitmap::from_iter([0])));
     }
 
     #[test]
@@ -12,8 +12,8 @@ use roaring::RoaringBitmap;
 
 use super::helpers::{
     self, merge_deladd_cbo_roaring_bitmaps_into_cbo_roaring_bitmap, valid_lmdb_key,
-    CursorClonableMmap, KeepFirst, MergeDeladdBtreesetString, MergeDeladdCboRoaringBitmaps,
-    MergeIgnoreValues,
+    valid_lmdb_word_pair_proximity_key, CursorClonableMmap, KeepFirst, MergeDeladdBtreesetString,
+    MergeDeladdCboRoaringBitmaps, MergeIgnoreValues,
 };
 use crate::external_documents_ids::{DocumentOperation, DocumentOperationKind};
 use crate::facet::FacetType;
@@ -495,10 +495,11 @@ pub(crate) fn write_typed_chunk_into_index(
                     wtxn,
                 )?;
             } else {
-                write_entries_into_database(
+                write_valid_entries_into_database(
                     merger,
                     &index.word_pair_proximity_docids,
                     wtxn,
+                    |key| valid_lmdb_word_pair_proximity_key(key),
                     deladd_serialize_add_side,
                     merge_deladd_cbo_roaring_bitmaps_into_cbo_roaring_bitmap,
                 )?;
@@ -778,13 +779,41 @@ where
     FM: for<'a> Fn(&[u8], &[u8], &'a mut Vec<u8>) -> Result<Option<&'a [u8]>>,
     MF: MergeFunction,
     crate::Error: From<MF::Error>,
+{
+    write_valid_entries_into_database(
+        merger,
+        database,
+        wtxn,
+        |key| valid_lmdb_key(key),
+        serialize_value,
+        merge_values,
+    )
+}
+
+/// Akin to the `write_entries_into_database` function but only the keys
+/// accepted by the valid_key function are written in the database.
+fn write_valid_entries_into_database<R, K, V, VK, FS, FM, MF>(
+    merger: Merger<R, MF>,
+    database: &heed::Database<K, V>,
+    wtxn: &mut RwTxn<'_>,
+    valid_key: VK,
+    serialize_value: FS,
+    merge_values: FM,
+) -> Result<()>
+where
+    R: io::Read + io::Seek,
+    VK: Fn(&[u8]) -> bool,
+    FS: for<'a> Fn(&'a [u8], &'a mut Vec<u8>) -> Result<&'a [u8]>,
+    FM: for<'a> Fn(&[u8], &[u8], &'a mut Vec<u8>) -> Result<Option<&'a [u8]>>,
+    MF: MergeFunction,
+    crate::Error: From<MF::Error>,
 {
     let mut buffer = Vec::new();
     let database = database.remap_types::<Bytes, Bytes>();
 
     let mut iter = merger.into_stream_merger_iter()?;
     while let Some((key, value)) = iter.next()? {
-        if valid_lmdb_key(key) {
+        if valid_key(key) {
             buffer.clear();
             let value = match database.get(wtxn, key)? {
                 Some(prev_value) => merge_values(value, prev_value, &mut buffer)?,
@@ -816,7 +845,7 @@ where
 {
     let mut iter = merger.into_stream_merger_iter()?;
     while let Some((key, value)) = iter.next()? {
-        if valid_lmdb_key(key) {
+        if valid_lmdb_word_pair_proximity_key(key) {
             let (proximity_to_insert, word1, word2) =
                 U8StrStrCodec::bytes_decode(key).map_err(heed::Error::Decoding)?;
             let data_to_insert = match KvReaderDelAdd::from_slice(value).get(DelAdd::Addition) {
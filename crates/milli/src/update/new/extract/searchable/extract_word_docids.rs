@@ -283,6 +283,7 @@ impl WordDocidsExtractors {
             attribute_to_skip: attributes_to_skip.as_slice(),
             localized_attributes_rules: &localized_attributes_rules,
             max_positions_per_attributes: MAX_POSITION_PER_ATTRIBUTE,
+            truncate_long_words: index.truncate_long_words(&rtxn)?,
         };
 
         let datastore = ThreadLocal::new();
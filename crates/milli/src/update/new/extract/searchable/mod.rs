@@ -93,6 +93,7 @@ pub trait SearchableExtractor: Sized + Sync {
             attribute_to_skip: attributes_to_skip.as_slice(),
             localized_attributes_rules: &localized_attributes_rules,
             max_positions_per_attributes: MAX_POSITION_PER_ATTRIBUTE,
+            truncate_long_words: indexing_context.index.truncate_long_words(&rtxn)?,
         };
 
         let extractor_data: SearchableExtractorData<Self> = SearchableExtractorData {
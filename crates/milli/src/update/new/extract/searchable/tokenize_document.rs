@@ -8,8 +8,8 @@ use crate::update::new::extract::perm_json_p::{
     seek_leaf_values_in_array, seek_leaf_values_in_object, select_field, Depth, Selection,
 };
 use crate::{
-    FieldId, GlobalFieldsIdsMap, InternalError, LocalizedAttributesRule, Result, UserError,
-    MAX_WORD_LENGTH,
+    indexable_word, FieldId, GlobalFieldsIdsMap, InternalError, LocalizedAttributesRule, Result,
+    UserError,
 };
 
 // todo: should be crate::proximity::MAX_DISTANCE but it has been forgotten
@@ -21,6 +21,7 @@ pub struct DocumentTokenizer<'a> {
     pub attribute_to_skip: &'a [&'a str],
     pub localized_attributes_rules: &'a [LocalizedAttributesRule],
     pub max_positions_per_attributes: u32,
+    pub truncate_long_words: bool,
 }
 
 impl<'a> DocumentTokenizer<'a> {
@@ -80,12 +81,11 @@ impl<'a> DocumentTokenizer<'a> {
                     .take_while(|(p, _)| *p < self.max_positions_per_attributes);
 
                 for (index, token) in tokens {
-                    // keep a word only if it is not empty and fit in a LMDB key.
-                    let token = token.lemma().trim();
-                    if !token.is_empty() && token.len() <= MAX_WORD_LENGTH {
+                    // keep a word only if it is not empty and fits in a LMDB key.
+                    if let Some(token) = indexable_word(token.lemma(), self.truncate_long_words) {
                         *position = index;
                         if let Ok(position) = (*position).try_into() {
-                            token_fn(field_name, field_id, position, token)?;
+                            token_fn(field_name, field_id, position, &token)?;
                         }
                     }
                 }
@@ -218,6 +218,7 @@ mod test {
             attribute_to_skip: &["not-me", "me-nether.nope"],
             localized_attributes_rules: &[],
             max_positions_per_attributes: 1000,
+            truncate_long_words: true,
         };
 
         let fields_ids_map = FieldIdMapWithMetadata::new(
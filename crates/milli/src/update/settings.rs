@@ -177,6 +177,7 @@ pub struct Settings<'a, 't, 'i> {
     embedder_settings: Setting<BTreeMap<String, Setting<EmbeddingSettings>>>,
     search_cutoff: Setting<u64>,
     localized_attributes_rules: Setting<Vec<LocalizedAttributesRule>>,
+    truncate_long_words: Setting<bool>,
 }
 
 impl<'a, 't, 'i> Settings<'a, 't, 'i> {
@@ -212,6 +213,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             embedder_settings: Setting::NotSet,
             search_cutoff: Setting::NotSet,
             localized_attributes_rules: Setting::NotSet,
+            truncate_long_words: Setting::NotSet,
             indexer_config,
         }
     }
@@ -394,6 +396,12 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.proximity_precision = Setting::Reset;
     }
 
+    /// Truncates the long words instead of dropping them, reindexing the searchable fields
+    /// of the indexes created before this behavior existed.
+    pub fn set_truncate_long_words(&mut self, value: bool) {
+        self.truncate_long_words = Setting::Set(value);
+    }
+
     pub fn set_embedder_settings(&mut self, value: BTreeMap<String, Setting<EmbeddingSettings>>) {
         self.embedder_settings = Setting::Set(value);
     }
@@ -954,6 +962,23 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         Ok(changed)
     }
 
+    fn update_truncate_long_words(&mut self) -> Result<bool> {
+        let changed = match self.truncate_long_words {
+            Setting::Set(new) => {
+                let old = self.index.truncate_long_words(self.wtxn)?;
+                if old == new {
+                    false
+                } else {
+                    self.index.put_truncate_long_words(self.wtxn, new)?;
+                    true
+                }
+            }
+            Setting::Reset | Setting::NotSet => false,
+        };
+
+        Ok(changed)
+    }
+
     fn update_embedding_configs(&mut self) -> Result<BTreeMap<String, EmbedderAction>> {
         match std::mem::take(&mut self.embedder_settings) {
             Setting::Set(configs) => self.update_embedding_configs_set(configs),
@@ -1204,6 +1229,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.update_exact_attributes()?;
         self.update_proximity_precision()?;
         self.update_localized_attributes_rules()?;
+        self.update_truncate_long_words()?;
 
         let embedding_config_updates = self.update_embedding_configs()?;
 
@@ -1242,7 +1268,7 @@ pub struct InnerIndexSettingsDiff {
     pub(crate) only_additional_fields: Option<HashSet<String>>,
 
     // Cache the check to see if all the stop_words, allowed_separators, dictionary,
-    // exact_attributes, proximity_precision are different.
+    // exact_attributes, proximity_precision, truncate_long_words are different.
     pub(crate) cache_reindex_searchable_without_user_defined: bool,
     // Cache the check to see if the user_defined_searchables are different.
     pub(crate) cache_user_defined_searchables: bool,
@@ -1284,6 +1310,7 @@ impl InnerIndexSettingsDiff {
                 || old_settings.proximity_precision != new_settings.proximity_precision
                 || old_settings.localized_searchable_fields_ids
                     != new_settings.localized_searchable_fields_ids
+                || old_settings.truncate_long_words != new_settings.truncate_long_words
         };
 
         let cache_exact_attributes = old_settings.exact_attributes != new_settings.exact_attributes;
@@ -1432,6 +1459,7 @@ pub(crate) struct InnerIndexSettings {
     pub non_faceted_fields_ids: Vec<FieldId>,
     pub localized_searchable_fields_ids: LocalizedFieldIds,
     pub localized_faceted_fields_ids: LocalizedFieldIds,
+    pub truncate_long_words: bool,
 }
 
 impl InnerIndexSettings {
@@ -1453,6 +1481,7 @@ impl InnerIndexSettings {
         let mut faceted_fields_ids = index.faceted_fields_ids(rtxn)?;
         let exact_attributes = index.exact_attributes_ids(rtxn)?;
         let proximity_precision = index.proximity_precision(rtxn)?.unwrap_or_default();
+        let truncate_long_words = index.truncate_long_words(rtxn)?;
         let embedding_configs = match embedding_configs {
             Some(embedding_configs) => embedding_configs,
             None => embedders(index.embedding_configs(rtxn)?)?,
@@ -1514,6 +1543,7 @@ impl InnerIndexSettings {
             non_faceted_fields_ids: vectors_fids.clone(),
             localized_searchable_fields_ids,
             localized_faceted_fields_ids,
+            truncate_long_words,
         })
     }
 
@@ -2721,6 +2751,7 @@ mod tests {
                     embedder_settings,
                     search_cutoff,
                     localized_attributes_rules,
+                    truncate_long_words,
                 } = settings;
                 assert!(matches!(searchable_fields, Setting::NotSet));
                 assert!(matches!(displayed_fields, Setting::NotSet));
@@ -2746,6 +2777,7 @@ mod tests {
                 assert!(matches!(embedder_settings, Setting::NotSet));
                 assert!(matches!(search_cutoff, Setting::NotSet));
                 assert!(matches!(localized_attributes_rules, Setting::NotSet));
+                assert!(matches!(truncate_long_words, Setting::NotSet));
             })
             .unwrap();
     }